@@ -1,6 +1,6 @@
 use crate::{
     core_3d::Opaque3d,
-    skybox::{SkyboxBindGroup, SkyboxPipelineId},
+    skybox::{low_res::SkyboxLowResPipelineId, SkyboxBindGroup, SkyboxPipelineId},
 };
 use bevy_ecs::{entity::Entity, prelude::World, query::QueryItem};
 use bevy_render::{
@@ -30,6 +30,7 @@ impl ViewNode for MainOpaquePass3dNode {
         &'static ViewDepthTexture,
         Option<&'static SkyboxPipelineId>,
         Option<&'static SkyboxBindGroup>,
+        Option<&'static SkyboxLowResPipelineId>,
         &'static ViewUniformOffset,
     );
 
@@ -44,6 +45,7 @@ impl ViewNode for MainOpaquePass3dNode {
             depth,
             skybox_pipeline,
             skybox_bind_group,
+            skybox_low_res_pipeline,
             view_uniform_offset,
         ): QueryItem<'w, Self::ViewQuery>,
         world: &'w World,
@@ -111,7 +113,15 @@ impl ViewNode for MainOpaquePass3dNode {
                 (skybox_pipeline, skybox_bind_group)
             {
                 let pipeline_cache = world.resource::<PipelineCache>();
-                if let Some(pipeline) = pipeline_cache.get_render_pipeline(skybox_pipeline.0) {
+                // A skybox rendered at a lower resolution can only be upscaled once it was drawn
+                let low_res_ready = match skybox_low_res_pipeline {
+                    Some(id) => pipeline_cache.get_render_pipeline(id.0).is_some(),
+                    None => true,
+                };
+                if let Some(pipeline) = pipeline_cache
+                    .get_render_pipeline(skybox_pipeline.0)
+                    .filter(|_| low_res_ready)
+                {
                     render_pass.set_render_pipeline(pipeline);
                     render_pass.set_bind_group(
                         0,
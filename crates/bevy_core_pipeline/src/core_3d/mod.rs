@@ -21,6 +21,7 @@ pub mod graph {
         CopyDeferredLightingId,
         EndPrepasses,
         StartMainPass,
+        SkyboxLowRes,
         MainOpaquePass,
         MainTransmissivePass,
         MainTransparentPass,
@@ -108,7 +109,7 @@ use crate::{
         NormalPrepass, Opaque3dPrepass, OpaqueNoLightmap3dBinKey, ViewPrepassTextures,
         MOTION_VECTOR_PREPASS_FORMAT, NORMAL_PREPASS_FORMAT,
     },
-    skybox::SkyboxPlugin,
+    skybox::{low_res::SkyboxLowResNode, SkyboxPlugin},
     tonemapping::TonemappingNode,
     upscaling::UpscalingNode,
 };
@@ -162,6 +163,7 @@ impl Plugin for Core3dPlugin {
             )
             .add_render_graph_node::<EmptyNode>(Core3d, Node3d::EndPrepasses)
             .add_render_graph_node::<EmptyNode>(Core3d, Node3d::StartMainPass)
+            .add_render_graph_node::<ViewNodeRunner<SkyboxLowResNode>>(Core3d, Node3d::SkyboxLowRes)
             .add_render_graph_node::<ViewNodeRunner<MainOpaquePass3dNode>>(
                 Core3d,
                 Node3d::MainOpaquePass,
@@ -187,6 +189,7 @@ impl Plugin for Core3dPlugin {
                     Node3d::CopyDeferredLightingId,
                     Node3d::EndPrepasses,
                     Node3d::StartMainPass,
+                    Node3d::SkyboxLowRes,
                     Node3d::MainOpaquePass,
                     Node3d::MainTransmissivePass,
                     Node3d::MainTransparentPass,
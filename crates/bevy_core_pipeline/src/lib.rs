@@ -20,13 +20,13 @@ pub mod fxaa;
 pub mod motion_blur;
 pub mod msaa_writeback;
 pub mod prepass;
-mod skybox;
+pub mod skybox;
 pub mod smaa;
 mod taa;
 pub mod tonemapping;
 pub mod upscaling;
 
-pub use skybox::Skybox;
+pub use skybox::{Skybox, SkyboxBuildError, SkyboxBuilder, SkyboxQuality, SkyboxSun};
 
 /// Experimental features that are not yet finished. Please report any issues you encounter!
 ///
@@ -45,6 +45,7 @@ pub mod prelude {
     pub use crate::{
         core_2d::{Camera2d, Camera2dBundle},
         core_3d::{Camera3d, Camera3dBundle},
+        skybox::{Skybox, SkyboxBuilder, SkyboxQuality, SkyboxSun},
     };
 }
 
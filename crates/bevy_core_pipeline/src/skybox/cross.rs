@@ -0,0 +1,286 @@
+use bevy_render::{
+    render_resource::{
+        Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor, TextureViewDimension,
+    },
+    texture::{Image, TextureFormatPixelInfo},
+};
+use thiserror::Error;
+
+/// Errors that can occur when converting an image with [`cubemap_from_cross`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CrossCubemapError {
+    /// The image is not a single 2D image, or it has mipmaps.
+    #[error("expected a single layer 2D image without mipmaps")]
+    NotSingle2dImage,
+    /// The image uses a block compressed format, whose pixels can't be rearranged.
+    #[error("cannot rearrange the faces of an image with the compressed format {0:?}")]
+    CompressedFormat(TextureFormat),
+    /// The image size doesn't match a horizontal or vertical cross of square faces.
+    #[error("expected a 4x3 or 3x4 cross of square faces, but the image is {width}x{height}")]
+    UnexpectedLayout {
+        /// The width of the image, in pixels.
+        width: u32,
+        /// The height of the image, in pixels.
+        height: u32,
+    },
+}
+
+/// Where a cubemap face is found within a cross layout image, in units of faces.
+struct CrossFace {
+    column: usize,
+    row: usize,
+    /// Whether the face is stored rotated by a half turn.
+    rotated: bool,
+}
+
+impl CrossFace {
+    const fn new(column: usize, row: usize) -> Self {
+        Self {
+            column,
+            row,
+            rotated: false,
+        }
+    }
+}
+
+/// The faces of a 4x3 cross, in cubemap layer order: +X, -X, +Y, -Y, +Z, -Z.
+const HORIZONTAL_CROSS: [CrossFace; 6] = [
+    CrossFace::new(2, 1),
+    CrossFace::new(0, 1),
+    CrossFace::new(1, 0),
+    CrossFace::new(1, 2),
+    CrossFace::new(1, 1),
+    CrossFace::new(3, 1),
+];
+
+/// The faces of a 3x4 cross, in cubemap layer order: +X, -X, +Y, -Y, +Z, -Z.
+///
+/// The -Z face hangs below -Y, so it is stored upside down.
+const VERTICAL_CROSS: [CrossFace; 6] = [
+    CrossFace::new(2, 1),
+    CrossFace::new(0, 1),
+    CrossFace::new(1, 0),
+    CrossFace::new(1, 2),
+    CrossFace::new(1, 1),
+    CrossFace {
+        column: 1,
+        row: 3,
+        rotated: true,
+    },
+];
+
+/// Converts a cubemap stored as a single cross-shaped image into a cube texture that can be
+/// used as a [`Skybox::image`](super::Skybox::image).
+///
+/// Both horizontal crosses (4 faces wide and 3 faces tall) and vertical crosses (3 faces wide
+/// and 4 faces tall) are supported, and the layout is detected from the image's aspect ratio.
+/// The faces are expected to follow the usual cross arrangement, with +Y above and -Y below
+/// the +Z face, -X to its left and +X to its right. The -Z face is either right of +X, or
+/// below -Y and upside down.
+///
+/// ```no_run
+/// # use bevy_core_pipeline::skybox::cubemap_from_cross;
+/// # use bevy_render::texture::Image;
+/// # let cross = Image::default();
+/// let cubemap = cubemap_from_cross(&cross).expect("the image should be a cubemap cross");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the image has several layers or mipmaps, uses a compressed format, or
+/// its size doesn't match either cross layout.
+pub fn cubemap_from_cross(image: &Image) -> Result<Image, CrossCubemapError> {
+    let descriptor = &image.texture_descriptor;
+    if descriptor.dimension != TextureDimension::D2
+        || descriptor.size.depth_or_array_layers != 1
+        || descriptor.mip_level_count != 1
+    {
+        return Err(CrossCubemapError::NotSingle2dImage);
+    }
+    if descriptor.format.block_dimensions() != (1, 1) {
+        return Err(CrossCubemapError::CompressedFormat(descriptor.format));
+    }
+
+    let (width, height) = (image.width(), image.height());
+    let (face_size, faces) = if width * 3 == height * 4 && width % 4 == 0 {
+        (width / 4, &HORIZONTAL_CROSS)
+    } else if width * 4 == height * 3 && width % 3 == 0 {
+        (width / 3, &VERTICAL_CROSS)
+    } else {
+        return Err(CrossCubemapError::UnexpectedLayout { width, height });
+    };
+
+    let pixel_size = descriptor.format.pixel_size();
+    let row_size = face_size as usize * pixel_size;
+    let image_row_size = width as usize * pixel_size;
+    let mut data = Vec::with_capacity(row_size * face_size as usize * faces.len());
+    for face in faces {
+        let face_start = face.row * face_size as usize * image_row_size + face.column * row_size;
+        for y in 0..face_size as usize {
+            if face.rotated {
+                // A half turn reverses both the order of the rows and the pixels within them.
+                let row_start = face_start + (face_size as usize - 1 - y) * image_row_size;
+                let row = &image.data[row_start..row_start + row_size];
+                for pixel in row.chunks_exact(pixel_size).rev() {
+                    data.extend_from_slice(pixel);
+                }
+            } else {
+                let row_start = face_start + y * image_row_size;
+                data.extend_from_slice(&image.data[row_start..row_start + row_size]);
+            }
+        }
+    }
+
+    let mut cubemap = Image::new(
+        Extent3d {
+            width: face_size,
+            height: face_size,
+            depth_or_array_layers: faces.len() as u32,
+        },
+        TextureDimension::D2,
+        data,
+        descriptor.format,
+        image.asset_usage,
+    );
+    cubemap.sampler = image.sampler.clone();
+    cubemap.texture_view_descriptor = Some(TextureViewDescriptor {
+        dimension: Some(TextureViewDimension::Cube),
+        ..Default::default()
+    });
+    Ok(cubemap)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_render::render_asset::RenderAssetUsages;
+
+    use super::*;
+
+    const FACE_SIZE: u32 = 2;
+
+    /// A single channel image made of `columns` by `rows` faces, where each pixel stores the
+    /// index of its face within the grid along with its position within the face.
+    fn grid_image(columns: u32, rows: u32) -> Image {
+        let (width, height) = (columns * FACE_SIZE, rows * FACE_SIZE);
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| pixel(x / FACE_SIZE, y / FACE_SIZE, x, y)))
+            .collect();
+        Image::new(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::R8Unorm,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    fn pixel(column: u32, row: u32, x: u32, y: u32) -> u8 {
+        let cell = row * 4 + column;
+        (cell * FACE_SIZE * FACE_SIZE + (y % FACE_SIZE) * FACE_SIZE + x % FACE_SIZE) as u8
+    }
+
+    /// The data of the face at `column` and `row` of a [`grid_image`], optionally turned by a
+    /// half turn.
+    fn face(column: u32, row: u32, rotated: bool) -> Vec<u8> {
+        (0..FACE_SIZE)
+            .flat_map(|y| {
+                (0..FACE_SIZE).map(move |x| {
+                    if rotated {
+                        pixel(column, row, FACE_SIZE - 1 - x, FACE_SIZE - 1 - y)
+                    } else {
+                        pixel(column, row, x, y)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn assert_cubemap(cubemap: &Image, faces: [Vec<u8>; 6]) {
+        assert_eq!(
+            cubemap.texture_descriptor.size,
+            Extent3d {
+                width: FACE_SIZE,
+                height: FACE_SIZE,
+                depth_or_array_layers: 6,
+            }
+        );
+        assert_eq!(
+            cubemap
+                .texture_view_descriptor
+                .as_ref()
+                .and_then(|descriptor| descriptor.dimension),
+            Some(TextureViewDimension::Cube)
+        );
+        assert_eq!(cubemap.data, faces.concat());
+    }
+
+    #[test]
+    fn horizontal_cross() {
+        let cubemap = cubemap_from_cross(&grid_image(4, 3)).unwrap();
+        assert_cubemap(
+            &cubemap,
+            [
+                face(2, 1, false),
+                face(0, 1, false),
+                face(1, 0, false),
+                face(1, 2, false),
+                face(1, 1, false),
+                face(3, 1, false),
+            ],
+        );
+    }
+
+    #[test]
+    fn vertical_cross() {
+        let cubemap = cubemap_from_cross(&grid_image(3, 4)).unwrap();
+        assert_cubemap(
+            &cubemap,
+            [
+                face(2, 1, false),
+                face(0, 1, false),
+                face(1, 0, false),
+                face(1, 2, false),
+                face(1, 1, false),
+                // -Z hangs below -Y upside down, reversing both its rows and its pixels
+                face(1, 3, true),
+            ],
+        );
+    }
+
+    #[test]
+    fn rejects_mipmapped_images() {
+        let mut image = grid_image(4, 3);
+        image.texture_descriptor.mip_level_count = 2;
+        assert_eq!(
+            cubemap_from_cross(&image).err(),
+            Some(CrossCubemapError::NotSingle2dImage)
+        );
+    }
+
+    #[test]
+    fn rejects_compressed_images() {
+        let mut image = grid_image(4, 3);
+        image.texture_descriptor.format = TextureFormat::Bc1RgbaUnorm;
+        assert_eq!(
+            cubemap_from_cross(&image).err(),
+            Some(CrossCubemapError::CompressedFormat(
+                TextureFormat::Bc1RgbaUnorm
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_other_layouts() {
+        assert_eq!(
+            cubemap_from_cross(&grid_image(4, 4)).err(),
+            Some(CrossCubemapError::UnexpectedLayout {
+                width: 4 * FACE_SIZE,
+                height: 4 * FACE_SIZE,
+            })
+        );
+    }
+}
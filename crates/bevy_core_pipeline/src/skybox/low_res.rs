@@ -0,0 +1,148 @@
+//! Renders skyboxes at a lower resolution, see [`Skybox::resolution_scale`].
+//!
+//! The sky is drawn into a small texture by [`SkyboxLowResNode`] before the main opaque pass,
+//! which then stretches it over the parts of the view that opaque geometry didn't cover.
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::QueryItem,
+    system::{Commands, Query, Res, ResMut},
+    world::World,
+};
+use bevy_math::UVec2;
+use bevy_render::{
+    render_asset::RenderAssets,
+    render_graph::{NodeRunError, RenderGraphContext, ViewNode},
+    render_resource::{
+        BindGroup, CachedRenderPipelineId, Extent3d, LoadOp, Operations, PipelineCache,
+        RenderPassColorAttachment, RenderPassDescriptor, StoreOp, TextureDescriptor,
+        TextureDimension, TextureFormat, TextureUsages,
+    },
+    renderer::{RenderContext, RenderDevice},
+    texture::{BevyDefault, CachedTexture, GpuImage, TextureCache},
+    view::{ExtractedView, ViewTarget, ViewUniformOffset},
+};
+
+use super::{Skybox, SkyboxBindGroup};
+
+/// The texture a view's [`Skybox`] is rendered into when its
+/// [`resolution_scale`](Skybox::resolution_scale) is below one.
+#[derive(Component)]
+pub struct SkyboxLowResTexture(pub CachedTexture);
+
+/// The ID of the [`SkyboxPipeline`](super::SkyboxPipeline) that renders a view's skybox into
+/// its [`SkyboxLowResTexture`].
+#[derive(Component)]
+pub struct SkyboxLowResPipelineId(pub CachedRenderPipelineId);
+
+/// The bind group used to render a view's skybox into its [`SkyboxLowResTexture`].
+///
+/// It takes the same dynamic offsets as the view's [`SkyboxBindGroup`].
+#[derive(Component)]
+pub struct SkyboxLowResBindGroup(pub BindGroup);
+
+/// Whether `skybox` is rendered at a lower resolution, which is only done once its image has
+/// loaded, as the fallback color is cheap to draw at any resolution.
+pub(super) fn uses_low_res(skybox: &Skybox, images: &RenderAssets<GpuImage>) -> bool {
+    skybox.resolution_scale < 1.0 && images.get(&skybox.image).is_some()
+}
+
+pub(super) fn prepare_skybox_low_res_textures(
+    mut commands: Commands,
+    mut texture_cache: ResMut<TextureCache>,
+    render_device: Res<RenderDevice>,
+    images: Res<RenderAssets<GpuImage>>,
+    views: Query<(Entity, &ExtractedView, &Skybox)>,
+) {
+    for (entity, view, skybox) in &views {
+        if !uses_low_res(skybox, &images) {
+            continue;
+        }
+
+        let viewport_size = UVec2::new(view.viewport.z, view.viewport.w);
+        let size = (viewport_size.as_vec2() * skybox.resolution_scale)
+            .ceil()
+            .as_uvec2()
+            .max(UVec2::ONE);
+        let texture = texture_cache.get(
+            &render_device,
+            TextureDescriptor {
+                label: Some("skybox_low_res_texture"),
+                size: Extent3d {
+                    width: size.x,
+                    height: size.y,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: if view.hdr {
+                    ViewTarget::TEXTURE_FORMAT_HDR
+                } else {
+                    TextureFormat::bevy_default()
+                },
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            },
+        );
+
+        commands.entity(entity).insert(SkyboxLowResTexture(texture));
+    }
+}
+
+/// A [`bevy_render::render_graph::Node`] that renders [`Skybox`]es with a
+/// [`resolution_scale`](Skybox::resolution_scale) below one into their
+/// [`SkyboxLowResTexture`], ahead of the main opaque pass that upscales them.
+#[derive(Default)]
+pub struct SkyboxLowResNode;
+
+impl ViewNode for SkyboxLowResNode {
+    type ViewQuery = (
+        &'static SkyboxLowResTexture,
+        &'static SkyboxLowResPipelineId,
+        &'static SkyboxLowResBindGroup,
+        &'static SkyboxBindGroup,
+        &'static ViewUniformOffset,
+    );
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext<'w>,
+        (texture, pipeline_id, bind_group, skybox_bind_group, view_uniform_offset): QueryItem<
+            'w,
+            Self::ViewQuery,
+        >,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id.0) else {
+            return Ok(());
+        };
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("skybox_low_res_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &texture.0.default_view,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Clear(Default::default()),
+                    store: StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(
+            0,
+            &bind_group.0,
+            &[view_uniform_offset.offset, skybox_bind_group.0 .1],
+        );
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
@@ -1,11 +1,16 @@
 use bevy_app::{App, Plugin};
-use bevy_asset::{load_internal_asset, Handle};
+use bevy_asset::{load_internal_asset, AssetId, Handle};
+use bevy_color::{Color, ColorToComponents, LinearRgba};
 use bevy_ecs::{
+    entity::EntityHashMap,
     prelude::{Component, Entity},
-    query::{QueryItem, With},
+    query::QueryItem,
+    reflect::ReflectComponent,
     schedule::IntoSystemConfigs,
-    system::{Commands, Query, Res, ResMut, Resource},
+    system::{Commands, Local, Query, Res, ResMut, Resource},
 };
+use bevy_math::{Vec3, Vec4};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{
     camera::Exposure,
     extract_component::{
@@ -14,27 +19,51 @@ use bevy_render::{
     },
     render_asset::RenderAssets,
     render_resource::{
-        binding_types::{sampler, texture_cube, uniform_buffer},
+        binding_types::{sampler, texture_2d, texture_cube, uniform_buffer},
         *,
     },
-    renderer::RenderDevice,
+    renderer::{RenderAdapter, RenderDevice},
     texture::{BevyDefault, GpuImage, Image},
     view::{ExtractedView, Msaa, ViewTarget, ViewUniform, ViewUniforms},
     Render, RenderApp, RenderSet,
 };
+use bevy_utils::{tracing::warn, HashMap};
+use low_res::{SkyboxLowResBindGroup, SkyboxLowResPipelineId, SkyboxLowResTexture};
 use prepass::{SkyboxPrepassPipeline, SKYBOX_PREPASS_SHADER_HANDLE};
+use std::f32::consts::PI;
+use thiserror::Error;
 
 use crate::core_3d::CORE_3D_DEPTH_FORMAT;
 
 const SKYBOX_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(55594763423201);
+const SKYBOX_FUNCTIONS_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(193048560153672481);
 
+/// The smallest sun radius passed to the shader, in radians. The cosine of anything smaller
+/// rounds to one, which leaves the disc's softened edge without any width.
+const SUN_MIN_ANGULAR_RADIUS: f32 = 1e-3;
+
+mod cross;
+pub mod low_res;
 pub mod prepass;
 
+pub use cross::{cubemap_from_cross, CrossCubemapError};
+
+/// Adds support for rendering a [`Skybox`].
+///
+/// This plugin is added automatically by [`Core3dPlugin`](crate::core_3d::Core3dPlugin), which
+/// is part of `DefaultPlugins`, so it only needs to be added manually when building a custom
+/// set of plugins without it.
 pub struct SkyboxPlugin;
 
 impl Plugin for SkyboxPlugin {
     fn build(&self, app: &mut App) {
         load_internal_asset!(app, SKYBOX_SHADER_HANDLE, "skybox.wgsl", Shader::from_wgsl);
+        load_internal_asset!(
+            app,
+            SKYBOX_FUNCTIONS_SHADER_HANDLE,
+            "skybox_functions.wgsl",
+            Shader::from_wgsl
+        );
         load_internal_asset!(
             app,
             SKYBOX_PREPASS_SHADER_HANDLE,
@@ -42,6 +71,10 @@ impl Plugin for SkyboxPlugin {
             Shader::from_wgsl
         );
 
+        app.register_type::<Skybox>()
+            .register_type::<SkyboxQuality>()
+            .register_type::<SkyboxSun>();
+
         app.add_plugins((
             ExtractComponentPlugin::<Skybox>::default(),
             UniformComponentPlugin::<SkyboxUniforms>::default(),
@@ -57,6 +90,7 @@ impl Plugin for SkyboxPlugin {
                 Render,
                 (
                     prepare_skybox_pipelines.in_set(RenderSet::Prepare),
+                    low_res::prepare_skybox_low_res_textures.in_set(RenderSet::PrepareResources),
                     prepass::prepare_skybox_prepass_pipelines.in_set(RenderSet::Prepare),
                     prepare_skybox_bind_groups.in_set(RenderSet::PrepareBindGroups),
                     prepass::prepare_skybox_prepass_bind_groups
@@ -70,8 +104,15 @@ impl Plugin for SkyboxPlugin {
             return;
         };
         let render_device = render_app.world().resource::<RenderDevice>().clone();
+        let anisotropic_filtering = render_app
+            .world()
+            .resource::<RenderAdapter>()
+            .get_downlevel_capabilities()
+            .flags
+            .contains(DownlevelFlags::ANISOTROPIC_FILTERING);
         render_app
             .insert_resource(SkyboxPipeline::new(&render_device))
+            .insert_resource(SkyboxSamplers::new(anisotropic_filtering))
             .init_resource::<SkyboxPrepassPipeline>();
     }
 }
@@ -81,14 +122,352 @@ impl Plugin for SkyboxPlugin {
 /// Note that this component does not (currently) affect the scene's lighting.
 /// To do so, use `EnvironmentMapLight` alongside this component.
 ///
+/// The skybox is only drawn for the camera it is added to, so cameras sharing a window, for
+/// example in split screen, can each show a different skybox, or none at all.
+///
 /// See also <https://en.wikipedia.org/wiki/Skybox_(video_games)>.
-#[derive(Component, Clone)]
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component, Default)]
 pub struct Skybox {
+    /// The cubemap to draw, an image with six layers whose
+    /// [`texture_view_descriptor`](Image::texture_view_descriptor) views it as a cube.
+    ///
+    /// KTX2 and DDS files can store cubemaps directly, and [`cubemap_from_cross`] converts
+    /// images laid out as a cross. HDR views, see [`Camera::hdr`](bevy_render::camera::Camera::hdr),
+    /// should use an image with a floating-point format such as
+    /// [`TextureFormat::Rgba16Float`], [`TextureFormat::Rg11b10Float`],
+    /// [`TextureFormat::Rgb9e5Ufloat`] or a BC6H format, as 8-bit formats can't store values
+    /// above one and show banding in dark skies. Conversely, the values of a floating-point
+    /// image are clipped in a non-HDR view, which isn't tonemapped. A warning is logged when an
+    /// 8-bit image is drawn in an HDR view.
     pub image: Handle<Image>,
     /// Scale factor applied to the skybox image.
     /// After applying this multiplier to the image samples, the resulting values should
     /// be in units of [cd/m^2](https://en.wikipedia.org/wiki/Candela_per_square_metre).
     pub brightness: f32,
+    /// If set, the part of the skybox below the horizon fades towards this color,
+    /// based on the pitch of the view direction.
+    ///
+    /// Use this to blend the skybox into distance fog, by setting it to the same color as
+    /// the fog. The alpha channel controls the strength of the blend.
+    ///
+    /// Defaults to `None`, which disables the fade.
+    pub horizon_fade: Option<Color>,
+    /// Trades skybox detail for less texture bandwidth, which helps fill-rate-limited
+    /// devices such as mobile GPUs.
+    pub quality: SkyboxQuality,
+    /// If set, the sky is filled with this color while [`Skybox::image`] is still loading.
+    ///
+    /// Defaults to `None`, in which case nothing is drawn until the image is ready, and
+    /// whatever was rendered behind the sky (usually the clear color) shows through.
+    pub fallback_color: Option<Color>,
+    /// If set, the skybox is exposed with this [EV100](Exposure::ev100) value instead of the
+    /// camera's [`Exposure`].
+    ///
+    /// This is useful when compositing the skybox into secondary render targets that don't
+    /// have an exposure of their own. Defaults to `None`, which uses the camera's exposure.
+    pub exposure_override: Option<f32>,
+    /// If set, a sun disc is drawn on top of the skybox.
+    ///
+    /// Defaults to `None`.
+    pub sun: Option<SkyboxSun>,
+    /// The fraction of the view's resolution the skybox is rendered at, between zero and one.
+    ///
+    /// Below one, the sky is rendered into a smaller texture which is then upscaled into the
+    /// view, saving fill rate on devices that are short of it. Since the sky has little fine
+    /// detail, values around `0.5` are barely noticeable, although a sharp [`Skybox::sun`]
+    /// edge will soften. Defaults to `1.0`, which renders the skybox directly into the view.
+    ///
+    /// Values above one are treated as one. Zero, negative and NaN values can't size a texture,
+    /// so they also render the skybox directly.
+    pub resolution_scale: f32,
+    /// If set, this shader replaces the fragment stage that draws the sky, e.g. to add a
+    /// procedural star field or aurora on top of the image.
+    ///
+    /// The shader must have a `skybox_fragment` entry point, taking the fragment's
+    /// `@builtin(position)` and returning the color at `@location(0)`. It can
+    /// `#import bevy_core_pipeline::skybox::{...}` to reuse the skybox's bindings and
+    /// building blocks:
+    ///
+    /// - `view` and `uniforms`, the view being drawn and the skybox's settings.
+    /// - `fragment_ray_direction(position)`, the world space direction of the sky at a fragment.
+    /// - `sample_skybox_image(ray_direction)`, the image sampled at [`Skybox::brightness`].
+    /// - `add_sun_disc(sky, ray_direction)`, which draws the [`Skybox::sun`].
+    /// - `apply_horizon_fade(sky, ray_direction)`, which applies [`Skybox::horizon_fade`].
+    ///
+    /// The default shader returns
+    /// `apply_horizon_fade(add_sun_disc(sky.rgb, ray_direction), ray_direction)`, with the
+    /// image's alpha, where `sky` is the sampled image. The shader isn't used while the image
+    /// is loading, nor to upscale a skybox with a [`Skybox::resolution_scale`] below one.
+    ///
+    /// Defaults to `None`, which uses the default shader.
+    pub fragment_shader: Option<Handle<Shader>>,
+    /// The maximum anisotropic filtering level used to sample the image, between 1 and 16.
+    ///
+    /// Anisotropic filtering keeps the sky sharp where it is viewed at a grazing angle, such as
+    /// near the horizon. Above one, the image is sampled with a trilinear sampler owned by the
+    /// skybox instead of the image's own [sampler](Image::sampler), and the level is clamped to
+    /// what the device supports. Devices without anisotropic filtering fall back to plain
+    /// trilinear filtering.
+    ///
+    /// Defaults to `1`, which samples the image with its own sampler.
+    pub anisotropy: u16,
+}
+
+impl Default for Skybox {
+    fn default() -> Self {
+        Skybox {
+            image: Handle::default(),
+            brightness: 0.0,
+            horizon_fade: None,
+            quality: SkyboxQuality::Full,
+            fallback_color: None,
+            exposure_override: None,
+            sun: None,
+            resolution_scale: 1.0,
+            fragment_shader: None,
+            anisotropy: 1,
+        }
+    }
+}
+
+impl Skybox {
+    /// The average [brightness](Skybox::brightness) of a clear daytime sky, in cd/m^2.
+    pub const BRIGHTNESS_CLEAR_DAY: f32 = 7000.0;
+    /// The average [brightness](Skybox::brightness) of an overcast daytime sky, in cd/m^2.
+    pub const BRIGHTNESS_OVERCAST_DAY: f32 = 2000.0;
+    /// The [brightness](Skybox::brightness) of a moonless night sky, in cd/m^2.
+    pub const BRIGHTNESS_NIGHT: f32 = 0.001;
+
+    /// Creates a [`Skybox`] whose [brightness](Skybox::brightness) is the luminance a
+    /// reflected light meter would measure as `ev100`, see [`Skybox::luminance_from_ev100`].
+    ///
+    /// Since a camera [`Exposure`] with the same EV100 is calibrated for that luminance, the
+    /// sky then renders as roughly middle grey at that exposure, while it appears brighter with
+    /// lower camera EV100 values and darker with higher ones.
+    pub fn from_ev100(image: Handle<Image>, ev100: f32) -> Self {
+        Skybox {
+            image,
+            brightness: Self::luminance_from_ev100(ev100),
+            ..Default::default()
+        }
+    }
+
+    /// Converts an EV100 value to a luminance in cd/m^2, suitable for [`Skybox::brightness`].
+    ///
+    /// This uses the reflected light meter equation `L = 2^EV100 * K / 100`, with the common
+    /// calibration constant `K = 12.5`, see
+    /// <https://google.github.io/filament/Filament.md.html#imagingpipeline/physicallybasedcamera/exposure>.
+    pub fn luminance_from_ev100(ev100: f32) -> f32 {
+        ev100.exp2() * 0.125
+    }
+
+    /// Starts building a [`Skybox`], validating its fields once [`SkyboxBuilder::build`] is
+    /// called.
+    ///
+    /// ```
+    /// # use bevy_asset::Handle;
+    /// # use bevy_core_pipeline::Skybox;
+    /// # let image = Handle::default();
+    /// let skybox = Skybox::builder()
+    ///     .image(image)
+    ///     .brightness(1000.0)
+    ///     .build()
+    ///     .expect("the skybox settings should be valid");
+    /// ```
+    pub fn builder() -> SkyboxBuilder {
+        SkyboxBuilder::default()
+    }
+}
+
+/// Builds a [`Skybox`], see [`Skybox::builder`].
+///
+/// The brightness must be set, with [`SkyboxBuilder::brightness`] or [`SkyboxBuilder::ev100`],
+/// since the default of zero draws a black sky. Other fields that aren't set keep their
+/// [`Default`] values.
+#[derive(Clone, Default)]
+pub struct SkyboxBuilder {
+    skybox: Skybox,
+    brightness: Option<f32>,
+}
+
+impl SkyboxBuilder {
+    /// Sets [`Skybox::image`].
+    pub fn image(mut self, image: Handle<Image>) -> Self {
+        self.skybox.image = image;
+        self
+    }
+
+    /// Sets [`Skybox::brightness`].
+    pub fn brightness(mut self, brightness: f32) -> Self {
+        self.brightness = Some(brightness);
+        self
+    }
+
+    /// Sets [`Skybox::brightness`] from an EV100 value, see [`Skybox::from_ev100`].
+    pub fn ev100(mut self, ev100: f32) -> Self {
+        self.brightness = Some(Skybox::luminance_from_ev100(ev100));
+        self
+    }
+
+    /// Sets [`Skybox::horizon_fade`].
+    pub fn horizon_fade(mut self, color: Color) -> Self {
+        self.skybox.horizon_fade = Some(color);
+        self
+    }
+
+    /// Sets [`Skybox::quality`].
+    pub fn quality(mut self, quality: SkyboxQuality) -> Self {
+        self.skybox.quality = quality;
+        self
+    }
+
+    /// Sets [`Skybox::fallback_color`].
+    pub fn fallback_color(mut self, color: Color) -> Self {
+        self.skybox.fallback_color = Some(color);
+        self
+    }
+
+    /// Sets [`Skybox::exposure_override`].
+    pub fn exposure_override(mut self, ev100: f32) -> Self {
+        self.skybox.exposure_override = Some(ev100);
+        self
+    }
+
+    /// Sets [`Skybox::sun`].
+    pub fn sun(mut self, sun: SkyboxSun) -> Self {
+        self.skybox.sun = Some(sun);
+        self
+    }
+
+    /// Sets [`Skybox::resolution_scale`].
+    pub fn resolution_scale(mut self, resolution_scale: f32) -> Self {
+        self.skybox.resolution_scale = resolution_scale;
+        self
+    }
+
+    /// Sets [`Skybox::fragment_shader`].
+    pub fn fragment_shader(mut self, shader: Handle<Shader>) -> Self {
+        self.skybox.fragment_shader = Some(shader);
+        self
+    }
+
+    /// Sets [`Skybox::anisotropy`].
+    pub fn anisotropy(mut self, anisotropy: u16) -> Self {
+        self.skybox.anisotropy = anisotropy;
+        self
+    }
+
+    /// Returns the configured [`Skybox`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the brightness wasn't set or is negative, if the sun's intensity is
+    /// negative, if the sun's angular radius isn't between zero and a half turn, if the
+    /// resolution scale isn't between zero and one, or if the anisotropy isn't between 1 and 16.
+    pub fn build(self) -> Result<Skybox, SkyboxBuildError> {
+        let mut skybox = self.skybox;
+        skybox.brightness = self.brightness.ok_or(SkyboxBuildError::MissingBrightness)?;
+        if skybox.brightness.is_nan() || skybox.brightness < 0.0 {
+            return Err(SkyboxBuildError::InvalidBrightness(skybox.brightness));
+        }
+        if !(skybox.resolution_scale > 0.0 && skybox.resolution_scale <= 1.0) {
+            return Err(SkyboxBuildError::InvalidResolutionScale(
+                skybox.resolution_scale,
+            ));
+        }
+        if !(1..=16).contains(&skybox.anisotropy) {
+            return Err(SkyboxBuildError::InvalidAnisotropy(skybox.anisotropy));
+        }
+        if let Some(sun) = &skybox.sun {
+            if sun.intensity.is_nan() || sun.intensity < 0.0 {
+                return Err(SkyboxBuildError::InvalidSunIntensity(sun.intensity));
+            }
+            if !(sun.angular_radius > 0.0 && sun.angular_radius < PI) {
+                return Err(SkyboxBuildError::InvalidSunAngularRadius(
+                    sun.angular_radius,
+                ));
+            }
+        }
+        Ok(skybox)
+    }
+}
+
+impl TryFrom<SkyboxBuilder> for Skybox {
+    type Error = SkyboxBuildError;
+
+    fn try_from(builder: SkyboxBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
+/// Errors that can occur when building a [`Skybox`] with [`SkyboxBuilder::build`].
+#[derive(Error, Debug, PartialEq)]
+pub enum SkyboxBuildError {
+    /// Neither [`SkyboxBuilder::brightness`] nor [`SkyboxBuilder::ev100`] was called.
+    #[error("the skybox brightness must be set")]
+    MissingBrightness,
+    /// The brightness is negative or NaN.
+    #[error("brightness must be non-negative, but was {0}")]
+    InvalidBrightness(f32),
+    /// The resolution scale isn't in `(0.0, 1.0]`.
+    #[error("resolution_scale must be in (0.0, 1.0], but was {0}")]
+    InvalidResolutionScale(f32),
+    /// The anisotropy isn't in `[1, 16]`.
+    #[error("anisotropy must be in [1, 16], but was {0}")]
+    InvalidAnisotropy(u16),
+    /// The sun's intensity is negative or NaN.
+    #[error("sun intensity must be non-negative, but was {0}")]
+    InvalidSunIntensity(f32),
+    /// The sun's angular radius isn't in `(0.0, PI)`.
+    #[error("sun angular_radius must be in (0.0, PI), but was {0}")]
+    InvalidSunAngularRadius(f32),
+}
+
+/// A sun disc drawn by a [`Skybox`], see [`Skybox::sun`].
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct SkyboxSun {
+    /// The world space direction pointing towards the sun.
+    ///
+    /// To match a [`DirectionalLight`](https://docs.rs/bevy/latest/bevy/pbr/struct.DirectionalLight.html),
+    /// use the opposite of the light's forward direction.
+    pub direction: Vec3,
+    /// The angular radius of the disc, in radians. The real sun is about 0.0047 radians.
+    pub angular_radius: f32,
+    /// The color of the disc.
+    pub color: Color,
+    /// The luminance of the disc, in [cd/m^2](https://en.wikipedia.org/wiki/Candela_per_square_metre).
+    ///
+    /// Like [`Skybox::brightness`], this is scaled by the camera's exposure.
+    pub intensity: f32,
+}
+
+/// The quality at which a [`Skybox`] is sampled.
+///
+/// Lower qualities bias sampling towards smaller mip levels of the skybox image, reducing
+/// the texture bandwidth used to draw the sky. This only has an effect if the image has
+/// mipmaps.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(Default, PartialEq, Hash)]
+pub enum SkyboxQuality {
+    /// Samples the skybox at full resolution.
+    #[default]
+    Full,
+    /// Samples the skybox at roughly half resolution, biasing sampling by one mip level.
+    Half,
+    /// Samples the skybox at roughly quarter resolution, biasing sampling by two mip levels.
+    Quarter,
+}
+
+impl SkyboxQuality {
+    /// The mip bias applied when sampling the skybox image at this quality.
+    fn mip_bias(self) -> u32 {
+        match self {
+            SkyboxQuality::Full => 0,
+            SkyboxQuality::Half => 1,
+            SkyboxQuality::Quarter => 2,
+        }
+    }
 }
 
 impl ExtractComponent for Skybox {
@@ -97,16 +476,65 @@ impl ExtractComponent for Skybox {
     type Out = (Self, SkyboxUniforms);
 
     fn extract_component((skybox, exposure): QueryItem<'_, Self::QueryData>) -> Option<Self::Out> {
-        let exposure = exposure
-            .map(|e| e.exposure())
-            .unwrap_or_else(|| Exposure::default().exposure());
+        let exposure = match skybox.exposure_override {
+            Some(ev100) => Exposure { ev100 }.exposure(),
+            None => exposure
+                .map(|e| e.exposure())
+                .unwrap_or_else(|| Exposure::default().exposure()),
+        };
+
+        // The sun's fields are public, and a NaN reaching the shader would turn the whole sky
+        // NaN, so drop a sun without a usable intensity or radius and clamp the radius.
+        let sun = skybox
+            .sun
+            .filter(|sun| {
+                sun.intensity.is_finite() && sun.intensity >= 0.0 && !sun.angular_radius.is_nan()
+            })
+            .map(|sun| SkyboxSun {
+                angular_radius: sun.angular_radius.clamp(SUN_MIN_ANGULAR_RADIUS, PI),
+                ..sun
+            });
+
+        // Like the sun, the resolution scale can be set directly, bypassing the builder's
+        // validation.
+        let resolution_scale = if skybox.resolution_scale > 0.0 {
+            skybox.resolution_scale.min(1.0)
+        } else {
+            1.0
+        };
 
         Some((
-            skybox.clone(),
+            Skybox {
+                resolution_scale,
+                ..skybox.clone()
+            },
             SkyboxUniforms {
-                brightness: skybox.brightness * exposure,
-                #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
-                _wasm_padding_8b: 0,
+                horizon_fade: skybox
+                    .horizon_fade
+                    .map(LinearRgba::from)
+                    .unwrap_or(LinearRgba::NONE)
+                    .to_vec4(),
+                fallback_color: skybox
+                    .fallback_color
+                    .map(LinearRgba::from)
+                    .unwrap_or(LinearRgba::BLACK)
+                    .to_vec4(),
+                sun_direction: sun
+                    .map(|sun| {
+                        sun.direction
+                            .normalize_or_zero()
+                            .extend(sun.angular_radius.cos())
+                    })
+                    .unwrap_or(Vec4::ZERO),
+                sun_color: sun
+                    .map(|sun| {
+                        (LinearRgba::from(sun.color).to_vec3() * sun.intensity * exposure)
+                            .extend(1.0)
+                    })
+                    .unwrap_or(Vec4::ZERO),
+                // Like the sun, the brightness is public. `max` also turns NaN into zero.
+                brightness: skybox.brightness.max(0.0) * exposure,
+                resolution_scale,
                 #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
                 _wasm_padding_12b: 0,
                 #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
@@ -119,22 +547,47 @@ impl ExtractComponent for Skybox {
 // TODO: Replace with a push constant once WebGPU gets support for that
 #[derive(Component, ShaderType, Clone)]
 pub struct SkyboxUniforms {
+    horizon_fade: Vec4,
+    fallback_color: Vec4,
+    /// The direction towards the sun in `xyz`, and the cosine of its angular radius in `w`.
+    sun_direction: Vec4,
+    /// The exposed color of the sun in `rgb`. `a` is zero if there is no sun.
+    sun_color: Vec4,
     brightness: f32,
-    #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
-    _wasm_padding_8b: u32,
+    resolution_scale: f32,
     #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
     _wasm_padding_12b: u32,
     #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
     _wasm_padding_16b: u32,
 }
 
+/// The render pipeline that draws a [`Skybox`] as a fullscreen triangle.
+///
+/// This is public so that custom render nodes, such as a planar reflection pass, can draw
+/// the skybox into their own render targets. Specialize it with a [`SkyboxPipelineKey`]
+/// matching the target, and build its bind group with [`SkyboxPipeline::create_bind_group`].
 #[derive(Resource)]
-struct SkyboxPipeline {
-    bind_group_layout: BindGroupLayout,
+pub struct SkyboxPipeline {
+    /// The layout of the skybox bind group:
+    ///
+    /// 0. The skybox cubemap texture.
+    /// 1. A filtering sampler for the cubemap.
+    /// 2. The [`ViewUniform`] of the view being drawn, with a dynamic offset.
+    /// 3. The [`SkyboxUniforms`] of the view being drawn, with a dynamic offset.
+    pub bind_group_layout: BindGroupLayout,
+    /// The layout of the bind group used while the skybox image is loading, see
+    /// [`Skybox::fallback_color`]. It only contains bindings 2 and 3 of
+    /// [`SkyboxPipeline::bind_group_layout`].
+    pub fallback_bind_group_layout: BindGroupLayout,
+    /// The layout of the bind group used to upscale a skybox rendered at a lower resolution,
+    /// see [`Skybox::resolution_scale`]. It matches [`SkyboxPipeline::bind_group_layout`],
+    /// except that bindings 0 and 1 are the low resolution 2D texture and its sampler.
+    upscale_bind_group_layout: BindGroupLayout,
+    upscale_sampler: Sampler,
 }
 
 impl SkyboxPipeline {
-    fn new(render_device: &RenderDevice) -> Self {
+    pub fn new(render_device: &RenderDevice) -> Self {
         Self {
             bind_group_layout: render_device.create_bind_group_layout(
                 "skybox_bind_group_layout",
@@ -149,24 +602,228 @@ impl SkyboxPipeline {
                     ),
                 ),
             ),
+            fallback_bind_group_layout: render_device.create_bind_group_layout(
+                "skybox_fallback_bind_group_layout",
+                &BindGroupLayoutEntries::with_indices(
+                    ShaderStages::FRAGMENT,
+                    (
+                        (
+                            2,
+                            uniform_buffer::<ViewUniform>(true)
+                                .visibility(ShaderStages::VERTEX_FRAGMENT),
+                        ),
+                        (3, uniform_buffer::<SkyboxUniforms>(true)),
+                    ),
+                ),
+            ),
+            upscale_bind_group_layout: render_device.create_bind_group_layout(
+                "skybox_upscale_bind_group_layout",
+                &BindGroupLayoutEntries::sequential(
+                    ShaderStages::FRAGMENT,
+                    (
+                        texture_2d(TextureSampleType::Float { filterable: true }),
+                        sampler(SamplerBindingType::Filtering),
+                        uniform_buffer::<ViewUniform>(true)
+                            .visibility(ShaderStages::VERTEX_FRAGMENT),
+                        uniform_buffer::<SkyboxUniforms>(true),
+                    ),
+                ),
+            ),
+            upscale_sampler: render_device.create_sampler(&SamplerDescriptor {
+                label: Some("skybox_upscale_sampler"),
+                mag_filter: FilterMode::Linear,
+                min_filter: FilterMode::Linear,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Creates a bind group matching [`SkyboxPipeline::bind_group_layout`].
+    ///
+    /// When drawing with it, pass the offset from the view's
+    /// [`ViewUniformOffset`](bevy_render::view::ViewUniformOffset), followed by the index from
+    /// its [`DynamicUniformIndex<SkyboxUniforms>`], as the dynamic offsets.
+    pub fn create_bind_group(
+        &self,
+        render_device: &RenderDevice,
+        skybox_image: &GpuImage,
+        view_uniforms: BindingResource,
+        skybox_uniforms: BindingResource,
+    ) -> BindGroup {
+        self.create_bind_group_with_sampler(
+            render_device,
+            skybox_image,
+            &skybox_image.sampler,
+            view_uniforms,
+            skybox_uniforms,
+        )
+    }
+
+    /// Creates a bind group matching [`SkyboxPipeline::bind_group_layout`], which samples
+    /// the skybox image with `sampler` instead of the image's own sampler.
+    ///
+    /// It takes the same dynamic offsets as [`SkyboxPipeline::create_bind_group`].
+    pub fn create_bind_group_with_sampler(
+        &self,
+        render_device: &RenderDevice,
+        skybox_image: &GpuImage,
+        sampler: &Sampler,
+        view_uniforms: BindingResource,
+        skybox_uniforms: BindingResource,
+    ) -> BindGroup {
+        render_device.create_bind_group(
+            "skybox_bind_group",
+            &self.bind_group_layout,
+            &BindGroupEntries::sequential((
+                &skybox_image.texture_view,
+                sampler,
+                view_uniforms,
+                skybox_uniforms,
+            )),
+        )
+    }
+
+    /// Creates a bind group matching [`SkyboxPipeline::fallback_bind_group_layout`], used with
+    /// a pipeline specialized with [`SkyboxPipelineKey::fallback`].
+    ///
+    /// It takes the same dynamic offsets as [`SkyboxPipeline::create_bind_group`].
+    pub fn create_fallback_bind_group(
+        &self,
+        render_device: &RenderDevice,
+        view_uniforms: BindingResource,
+        skybox_uniforms: BindingResource,
+    ) -> BindGroup {
+        render_device.create_bind_group(
+            "skybox_fallback_bind_group",
+            &self.fallback_bind_group_layout,
+            &BindGroupEntries::with_indices(((2, view_uniforms), (3, skybox_uniforms))),
+        )
+    }
+
+    /// Creates the bind group that upscales `low_res_texture` into the view, used with a
+    /// pipeline specialized with [`SkyboxPass::Upscale`].
+    ///
+    /// It takes the same dynamic offsets as [`SkyboxPipeline::create_bind_group`].
+    fn create_upscale_bind_group(
+        &self,
+        render_device: &RenderDevice,
+        low_res_texture: &TextureView,
+        view_uniforms: BindingResource,
+        skybox_uniforms: BindingResource,
+    ) -> BindGroup {
+        render_device.create_bind_group(
+            "skybox_upscale_bind_group",
+            &self.upscale_bind_group_layout,
+            &BindGroupEntries::sequential((
+                low_res_texture,
+                &self.upscale_sampler,
+                view_uniforms,
+                skybox_uniforms,
+            )),
+        )
+    }
+}
+
+/// The anisotropic samplers used for [`Skybox`]es with an [`anisotropy`](Skybox::anisotropy)
+/// above one, created as they are needed.
+#[derive(Resource)]
+struct SkyboxSamplers {
+    /// The highest anisotropy level the device supports, which is one if it doesn't support
+    /// anisotropic filtering at all.
+    max_anisotropy: u16,
+    samplers: HashMap<u16, Sampler>,
+}
+
+impl SkyboxSamplers {
+    fn new(anisotropic_filtering: bool) -> Self {
+        Self {
+            max_anisotropy: if anisotropic_filtering { 16 } else { 1 },
+            samplers: HashMap::default(),
         }
     }
+
+    /// Returns the trilinear sampler for `anisotropy`, clamped to the device's support.
+    fn get(&mut self, render_device: &RenderDevice, anisotropy: u16) -> &Sampler {
+        let anisotropy = anisotropy.clamp(1, self.max_anisotropy);
+        self.samplers.entry(anisotropy).or_insert_with(|| {
+            render_device.create_sampler(&SamplerDescriptor {
+                label: Some("skybox_anisotropic_sampler"),
+                mag_filter: FilterMode::Linear,
+                min_filter: FilterMode::Linear,
+                mipmap_filter: FilterMode::Linear,
+                anisotropy_clamp: anisotropy,
+                ..Default::default()
+            })
+        })
+    }
 }
 
+/// The key used to specialize the [`SkyboxPipeline`].
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
-struct SkyboxPipelineKey {
-    hdr: bool,
-    samples: u32,
-    depth_format: TextureFormat,
+pub struct SkyboxPipelineKey {
+    /// Whether the render target uses [`ViewTarget::TEXTURE_FORMAT_HDR`].
+    pub hdr: bool,
+    /// The MSAA sample count of the render target.
+    pub samples: u32,
+    /// The format of the depth attachment the skybox is tested against.
+    pub depth_format: TextureFormat,
+    /// The [`SkyboxQuality`] to sample the skybox at.
+    pub quality: SkyboxQuality,
+    /// Whether to fill the sky with [`Skybox::fallback_color`] instead of sampling the image.
+    pub fallback: bool,
+    /// The pass the skybox is drawn in.
+    pub pass: SkyboxPass,
+    /// The custom fragment shader to draw the sky with, see [`Skybox::fragment_shader`].
+    ///
+    /// It is ignored when [`SkyboxPipelineKey::fallback`] is set, and for
+    /// [`SkyboxPass::Upscale`].
+    pub fragment_shader: Option<AssetId<Shader>>,
+}
+
+/// The pass a [`SkyboxPipeline`] draws in, see [`Skybox::resolution_scale`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub enum SkyboxPass {
+    /// Draws the skybox directly into the view, behind opaque geometry.
+    #[default]
+    Direct,
+    /// Draws the skybox into a lower resolution texture, without depth testing or MSAA.
+    ///
+    /// The [`SkyboxPipelineKey::hdr`] and [`SkyboxPipelineKey::quality`] still apply, while
+    /// [`SkyboxPipelineKey::samples`] and [`SkyboxPipelineKey::depth_format`] are ignored.
+    LowRes,
+    /// Upscales the sky drawn by [`SkyboxPass::LowRes`] into the view, behind opaque geometry.
+    Upscale,
 }
 
 impl SpecializedRenderPipeline for SkyboxPipeline {
     type Key = SkyboxPipelineKey;
 
     fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let mut shader_defs = Vec::new();
+        if key.fallback {
+            shader_defs.push("SKYBOX_FALLBACK".into());
+        }
+        match key.pass {
+            SkyboxPass::Direct => {}
+            SkyboxPass::LowRes => shader_defs.push("SKYBOX_LOW_RES".into()),
+            SkyboxPass::Upscale => shader_defs.push("SKYBOX_UPSCALE".into()),
+        }
+        if key.quality != SkyboxQuality::Full {
+            shader_defs.push(ShaderDefVal::UInt(
+                "SKYBOX_MIP_BIAS".into(),
+                key.quality.mip_bias(),
+            ));
+        }
+
         RenderPipelineDescriptor {
             label: Some("skybox_pipeline".into()),
-            layout: vec![self.bind_group_layout.clone()],
+            layout: vec![if key.pass == SkyboxPass::Upscale {
+                self.upscale_bind_group_layout.clone()
+            } else if key.fallback {
+                self.fallback_bind_group_layout.clone()
+            } else {
+                self.bind_group_layout.clone()
+            }],
             push_constant_ranges: Vec::new(),
             vertex: VertexState {
                 shader: SKYBOX_SHADER_HANDLE,
@@ -175,7 +832,8 @@ impl SpecializedRenderPipeline for SkyboxPipeline {
                 buffers: Vec::new(),
             },
             primitive: PrimitiveState::default(),
-            depth_stencil: Some(DepthStencilState {
+            // The low resolution sky is drawn before anything else, so it covers everything.
+            depth_stencil: (key.pass != SkyboxPass::LowRes).then_some(DepthStencilState {
                 format: key.depth_format,
                 depth_write_enabled: false,
                 depth_compare: CompareFunction::GreaterEqual,
@@ -192,13 +850,22 @@ impl SpecializedRenderPipeline for SkyboxPipeline {
                 },
             }),
             multisample: MultisampleState {
-                count: key.samples,
+                count: if key.pass == SkyboxPass::LowRes {
+                    1
+                } else {
+                    key.samples
+                },
                 mask: !0,
                 alpha_to_coverage_enabled: false,
             },
             fragment: Some(FragmentState {
-                shader: SKYBOX_SHADER_HANDLE,
-                shader_defs: Vec::new(),
+                shader: match key.fragment_shader {
+                    Some(shader) if !key.fallback && key.pass != SkyboxPass::Upscale => {
+                        Handle::Weak(shader)
+                    }
+                    _ => SKYBOX_SHADER_HANDLE,
+                },
+                shader_defs,
                 entry_point: "skybox_fragment".into(),
                 targets: vec![Some(ColorTargetState {
                     format: if key.hdr {
@@ -215,66 +882,256 @@ impl SpecializedRenderPipeline for SkyboxPipeline {
     }
 }
 
+/// Whether images with `format` can store the high dynamic range values drawn in an HDR view.
+fn is_hdr_format(format: TextureFormat) -> bool {
+    match format {
+        TextureFormat::R16Float
+        | TextureFormat::Rg16Float
+        | TextureFormat::Rgba16Float
+        | TextureFormat::R32Float
+        | TextureFormat::Rg32Float
+        | TextureFormat::Rgba32Float
+        | TextureFormat::Rg11b10Float
+        | TextureFormat::Rgb9e5Ufloat
+        | TextureFormat::Bc6hRgbUfloat
+        | TextureFormat::Bc6hRgbFloat => true,
+        TextureFormat::Astc { channel, .. } => channel == AstcChannel::Hdr,
+        _ => false,
+    }
+}
+
 #[derive(Component)]
 pub struct SkyboxPipelineId(pub CachedRenderPipelineId);
 
+#[allow(clippy::too_many_arguments)]
 fn prepare_skybox_pipelines(
     mut commands: Commands,
     pipeline_cache: Res<PipelineCache>,
     mut pipelines: ResMut<SpecializedRenderPipelines<SkyboxPipeline>>,
     pipeline: Res<SkyboxPipeline>,
     msaa: Res<Msaa>,
-    views: Query<(Entity, &ExtractedView), With<Skybox>>,
+    images: Res<RenderAssets<GpuImage>>,
+    views: Query<(Entity, &ExtractedView, &Skybox)>,
+    mut checked_formats: Local<EntityHashMap<(AssetId<Image>, bool)>>,
 ) {
-    for (entity, view) in &views {
-        let pipeline_id = pipelines.specialize(
-            &pipeline_cache,
-            &pipeline,
-            SkyboxPipelineKey {
-                hdr: view.hdr,
-                samples: msaa.samples(),
-                depth_format: CORE_3D_DEPTH_FORMAT,
-            },
-        );
+    checked_formats.retain(|entity, _| views.contains(*entity));
 
-        commands
-            .entity(entity)
-            .insert(SkyboxPipelineId(pipeline_id));
+    for (entity, view, skybox) in &views {
+        let image = images.get(&skybox.image);
+        if let Some(image) = image {
+            // Only check the format again once the view's image or HDR setting changes.
+            let checked = (skybox.image.id(), view.hdr);
+            if checked_formats.insert(entity, checked) != Some(checked)
+                && view.hdr
+                && !is_hdr_format(image.texture_format)
+            {
+                warn!(
+                    "A Skybox image with the non-HDR format {:?} is drawn in an HDR view, so it \
+                    can't store bright skies precisely. Use a floating-point format instead.",
+                    image.texture_format
+                );
+            }
+        }
+
+        let fallback = image.is_none();
+        if fallback && skybox.fallback_color.is_none() {
+            continue;
+        }
+
+        let key = SkyboxPipelineKey {
+            hdr: view.hdr,
+            samples: msaa.samples(),
+            depth_format: CORE_3D_DEPTH_FORMAT,
+            quality: skybox.quality,
+            fallback,
+            pass: SkyboxPass::Direct,
+            fragment_shader: skybox.fragment_shader.as_ref().map(Handle::id),
+        };
+
+        if low_res::uses_low_res(skybox, &images) {
+            let low_res_pipeline_id = pipelines.specialize(
+                &pipeline_cache,
+                &pipeline,
+                SkyboxPipelineKey {
+                    pass: SkyboxPass::LowRes,
+                    ..key
+                },
+            );
+            let pipeline_id = pipelines.specialize(
+                &pipeline_cache,
+                &pipeline,
+                SkyboxPipelineKey {
+                    pass: SkyboxPass::Upscale,
+                    // Avoids specializing the same upscale pipeline once per custom shader
+                    fragment_shader: None,
+                    ..key
+                },
+            );
+            commands.entity(entity).insert((
+                SkyboxPipelineId(pipeline_id),
+                SkyboxLowResPipelineId(low_res_pipeline_id),
+            ));
+        } else {
+            let pipeline_id = pipelines.specialize(&pipeline_cache, &pipeline, key);
+            commands
+                .entity(entity)
+                .insert(SkyboxPipelineId(pipeline_id));
+        }
     }
 }
 
 #[derive(Component)]
 pub struct SkyboxBindGroup(pub (BindGroup, u32));
 
+#[allow(clippy::too_many_arguments)]
 fn prepare_skybox_bind_groups(
     mut commands: Commands,
     pipeline: Res<SkyboxPipeline>,
     view_uniforms: Res<ViewUniforms>,
     skybox_uniforms: Res<ComponentUniforms<SkyboxUniforms>>,
     images: Res<RenderAssets<GpuImage>>,
+    mut samplers: ResMut<SkyboxSamplers>,
     render_device: Res<RenderDevice>,
-    views: Query<(Entity, &Skybox, &DynamicUniformIndex<SkyboxUniforms>)>,
+    views: Query<(
+        Entity,
+        &Skybox,
+        &DynamicUniformIndex<SkyboxUniforms>,
+        Option<&SkyboxLowResTexture>,
+    )>,
 ) {
-    for (entity, skybox, skybox_uniform_index) in &views {
-        if let (Some(skybox), Some(view_uniforms), Some(skybox_uniforms)) = (
-            images.get(&skybox.image),
-            view_uniforms.uniforms.binding(),
-            skybox_uniforms.binding(),
-        ) {
-            let bind_group = render_device.create_bind_group(
-                "skybox_bind_group",
-                &pipeline.bind_group_layout,
-                &BindGroupEntries::sequential((
-                    &skybox.texture_view,
-                    &skybox.sampler,
+    for (entity, skybox, skybox_uniform_index, low_res_texture) in &views {
+        let (Some(view_uniforms), Some(skybox_uniforms)) =
+            (view_uniforms.uniforms.binding(), skybox_uniforms.binding())
+        else {
+            continue;
+        };
+        let image = images.get(&skybox.image).map(|image| {
+            let sampler = if skybox.anisotropy > 1 {
+                samplers.get(&render_device, skybox.anisotropy)
+            } else {
+                &image.sampler
+            };
+            (image, sampler)
+        });
+
+        let bind_group = match (image, low_res_texture) {
+            // The sky is drawn into the low resolution texture first, then upscaled into the view.
+            (Some((image, sampler)), Some(low_res_texture)) => {
+                let low_res_bind_group = pipeline.create_bind_group_with_sampler(
+                    &render_device,
+                    image,
+                    sampler,
+                    view_uniforms.clone(),
+                    skybox_uniforms.clone(),
+                );
+                commands
+                    .entity(entity)
+                    .insert(SkyboxLowResBindGroup(low_res_bind_group));
+                pipeline.create_upscale_bind_group(
+                    &render_device,
+                    &low_res_texture.0.default_view,
                     view_uniforms,
                     skybox_uniforms,
-                )),
-            );
+                )
+            }
+            (Some((image, sampler)), None) => pipeline.create_bind_group_with_sampler(
+                &render_device,
+                image,
+                sampler,
+                view_uniforms,
+                skybox_uniforms,
+            ),
+            (None, _) if skybox.fallback_color.is_some() => {
+                pipeline.create_fallback_bind_group(&render_device, view_uniforms, skybox_uniforms)
+            }
+            (None, _) => continue,
+        };
 
-            commands
-                .entity(entity)
-                .insert(SkyboxBindGroup((bind_group, skybox_uniform_index.index())));
+        commands
+            .entity(entity)
+            .insert(SkyboxBindGroup((bind_group, skybox_uniform_index.index())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sun() -> SkyboxSun {
+        SkyboxSun {
+            direction: Vec3::Y,
+            angular_radius: 0.01,
+            color: Color::WHITE,
+            intensity: 1000.0,
         }
     }
+
+    #[test]
+    fn build_valid_skybox() {
+        let skybox = Skybox::builder()
+            .ev100(10.0)
+            .resolution_scale(0.5)
+            .anisotropy(16)
+            .sun(sun())
+            .build()
+            .unwrap();
+        assert_eq!(skybox.brightness, Skybox::luminance_from_ev100(10.0));
+        assert_eq!(skybox.resolution_scale, 0.5);
+        assert_eq!(skybox.anisotropy, 16);
+        assert!(skybox.sun.is_some());
+    }
+
+    #[test]
+    fn build_requires_brightness() {
+        assert_eq!(
+            Skybox::builder().build().err(),
+            Some(SkyboxBuildError::MissingBrightness)
+        );
+    }
+
+    #[test]
+    fn build_rejects_invalid_settings() {
+        let builder = Skybox::builder().brightness(1000.0);
+        assert_eq!(
+            builder.clone().brightness(-1.0).build().err(),
+            Some(SkyboxBuildError::InvalidBrightness(-1.0))
+        );
+        assert_eq!(
+            builder.clone().resolution_scale(0.0).build().err(),
+            Some(SkyboxBuildError::InvalidResolutionScale(0.0))
+        );
+        assert_eq!(
+            builder.clone().resolution_scale(1.5).build().err(),
+            Some(SkyboxBuildError::InvalidResolutionScale(1.5))
+        );
+        assert_eq!(
+            builder.clone().anisotropy(0).build().err(),
+            Some(SkyboxBuildError::InvalidAnisotropy(0))
+        );
+        assert_eq!(
+            builder.clone().anisotropy(17).build().err(),
+            Some(SkyboxBuildError::InvalidAnisotropy(17))
+        );
+        assert_eq!(
+            builder
+                .clone()
+                .sun(SkyboxSun {
+                    intensity: -1.0,
+                    ..sun()
+                })
+                .build()
+                .err(),
+            Some(SkyboxBuildError::InvalidSunIntensity(-1.0))
+        );
+        assert_eq!(
+            builder
+                .sun(SkyboxSun {
+                    angular_radius: 0.0,
+                    ..sun()
+                })
+                .build()
+                .err(),
+            Some(SkyboxBuildError::InvalidSunAngularRadius(0.0))
+        );
+    }
 }
@@ -31,6 +31,7 @@ use crate::{
     Skybox,
 };
 
+/// The handle of the shader that writes the skybox's motion vectors, see [`SkyboxPrepassPipeline`].
 pub const SKYBOX_PREPASS_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(376510055324461154);
 
 /// This pipeline writes motion vectors to the prepass for all [`Skybox`]es.
@@ -1,43 +1,11 @@
-#import bevy_render::view::View
-#import bevy_pbr::utils::coords_to_viewport_uv
-
-struct SkyboxUniforms {
-	brightness: f32,
-#ifdef SIXTEEN_BYTE_ALIGNMENT
-	_wasm_padding_8b: u32,
-	_wasm_padding_12b: u32,
-	_wasm_padding_16b: u32,
-#endif
-}
-
-@group(0) @binding(0) var skybox: texture_cube<f32>;
-@group(0) @binding(1) var skybox_sampler: sampler;
-@group(0) @binding(2) var<uniform> view: View;
-@group(0) @binding(3) var<uniform> uniforms: SkyboxUniforms;
-
-fn coords_to_ray_direction(position: vec2<f32>, viewport: vec4<f32>) -> vec3<f32> {
-    // Using world positions of the fragment and camera to calculate a ray direction
-    // breaks down at large translations. This code only needs to know the ray direction.
-    // The ray direction is along the direction from the camera to the fragment position.
-    // In view space, the camera is at the origin, so the view space ray direction is
-    // along the direction of the fragment position - (0,0,0) which is just the
-    // fragment position.
-    // Use the position on the near clipping plane to avoid -inf world position
-    // because the far plane of an infinite reverse projection is at infinity.
-    let view_position_homogeneous = view.view_from_clip * vec4(
-        coords_to_viewport_uv(position, viewport) * vec2(2.0, -2.0) + vec2(-1.0, 1.0),
-        1.0,
-        1.0,
-    );
-    let view_ray_direction = view_position_homogeneous.xyz / view_position_homogeneous.w;
-    // Transforming the view space ray direction by the view matrix, transforms the
-    // direction to world space. Note that the w element is set to 0.0, as this is a
-    // vector direction, not a position, That causes the matrix multiplication to ignore
-    // the translations from the view matrix.
-    let ray_direction = (view.world_from_view * vec4(view_ray_direction, 0.0)).xyz;
-
-    return normalize(ray_direction);
+#import bevy_core_pipeline::skybox::{
+    add_sun_disc, apply_horizon_fade, fragment_ray_direction, uniforms, view,
 }
+#ifdef SKYBOX_UPSCALE
+#import bevy_core_pipeline::skybox::{low_res_sky, low_res_sky_sampler}
+#else ifndef SKYBOX_FALLBACK
+#import bevy_core_pipeline::skybox::sample_skybox_image
+#endif
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
@@ -69,9 +37,19 @@ fn skybox_vertex(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
 
 @fragment
 fn skybox_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
-    let ray_direction = coords_to_ray_direction(in.position.xy, view.viewport);
-
-    // Cube maps are left-handed so we negate the z coordinate.
-    let out = textureSample(skybox, skybox_sampler, ray_direction * vec3(1.0, 1.0, -1.0));
-    return vec4(out.rgb * uniforms.brightness, out.a);
+#ifdef SKYBOX_UPSCALE
+    // Stretch the sky drawn at a lower resolution over the view. Each of its pixels covers
+    // `1.0 / resolution_scale` pixels of the view.
+    let uv = (in.position.xy - view.viewport.xy) * uniforms.resolution_scale
+        / vec2<f32>(textureDimensions(low_res_sky));
+    return textureSample(low_res_sky, low_res_sky_sampler, uv);
+#else ifdef SKYBOX_FALLBACK
+    // The skybox image is still loading.
+    return uniforms.fallback_color;
+#else
+    let ray_direction = fragment_ray_direction(in.position.xy);
+    let out = sample_skybox_image(ray_direction);
+    let sky = apply_horizon_fade(add_sun_disc(out.rgb, ray_direction), ray_direction);
+    return vec4(sky, out.a);
+#endif
 }
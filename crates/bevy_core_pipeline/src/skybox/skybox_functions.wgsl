@@ -0,0 +1,107 @@
+#define_import_path bevy_core_pipeline::skybox
+
+#import bevy_render::view::View
+#import bevy_pbr::utils::coords_to_viewport_uv
+
+struct SkyboxUniforms {
+	horizon_fade: vec4<f32>,
+	fallback_color: vec4<f32>,
+	sun_direction: vec4<f32>,
+	sun_color: vec4<f32>,
+	brightness: f32,
+	resolution_scale: f32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+	_wasm_padding_12b: u32,
+	_wasm_padding_16b: u32,
+#endif
+}
+
+#ifdef SKYBOX_UPSCALE
+@group(0) @binding(0) var low_res_sky: texture_2d<f32>;
+@group(0) @binding(1) var low_res_sky_sampler: sampler;
+#else
+#ifndef SKYBOX_FALLBACK
+@group(0) @binding(0) var skybox: texture_cube<f32>;
+@group(0) @binding(1) var skybox_sampler: sampler;
+#endif
+#endif
+@group(0) @binding(2) var<uniform> view: View;
+@group(0) @binding(3) var<uniform> uniforms: SkyboxUniforms;
+
+// How far above the horizon, as the y component of the ray direction, the horizon fade reaches.
+const HORIZON_FADE_HEIGHT: f32 = 0.25;
+// The width of the antialiased edge of the sun disc, as a fraction of its angular radius.
+const SUN_EDGE_WIDTH: f32 = 0.1;
+
+fn coords_to_ray_direction(position: vec2<f32>, viewport: vec4<f32>) -> vec3<f32> {
+    // Using world positions of the fragment and camera to calculate a ray direction
+    // breaks down at large translations. This code only needs to know the ray direction.
+    // The ray direction is along the direction from the camera to the fragment position.
+    // In view space, the camera is at the origin, so the view space ray direction is
+    // along the direction of the fragment position - (0,0,0) which is just the
+    // fragment position.
+    // Use the position on the near clipping plane to avoid -inf world position
+    // because the far plane of an infinite reverse projection is at infinity.
+    let view_position_homogeneous = view.view_from_clip * vec4(
+        coords_to_viewport_uv(position, viewport) * vec2(2.0, -2.0) + vec2(-1.0, 1.0),
+        1.0,
+        1.0,
+    );
+    let view_ray_direction = view_position_homogeneous.xyz / view_position_homogeneous.w;
+    // Transforming the view space ray direction by the view matrix, transforms the
+    // direction to world space. Note that the w element is set to 0.0, as this is a
+    // vector direction, not a position, That causes the matrix multiplication to ignore
+    // the translations from the view matrix.
+    let ray_direction = (view.world_from_view * vec4(view_ray_direction, 0.0)).xyz;
+
+    return normalize(ray_direction);
+}
+
+// The normalized world space direction of the sky seen at the fragment `position`.
+fn fragment_ray_direction(position: vec2<f32>) -> vec3<f32> {
+#ifdef SKYBOX_LOW_RES
+    // The low resolution texture covers the view's viewport, scaled down.
+    let viewport = vec4(vec2(0.0), view.viewport.zw * uniforms.resolution_scale);
+#else
+    let viewport = view.viewport;
+#endif
+    return coords_to_ray_direction(position, viewport);
+}
+
+#ifndef SKYBOX_UPSCALE
+#ifndef SKYBOX_FALLBACK
+// Samples the skybox image along `ray_direction`, scaled by its exposed brightness.
+fn sample_skybox_image(ray_direction: vec3<f32>) -> vec4<f32> {
+    // Cube maps are left-handed so we negate the z coordinate.
+#ifdef SKYBOX_MIP_BIAS
+    let out = textureSampleBias(
+        skybox,
+        skybox_sampler,
+        ray_direction * vec3(1.0, 1.0, -1.0),
+        f32(#{SKYBOX_MIP_BIAS}u),
+    );
+#else
+    let out = textureSample(skybox, skybox_sampler, ray_direction * vec3(1.0, 1.0, -1.0));
+#endif
+    return vec4(out.rgb * uniforms.brightness, out.a);
+}
+#endif
+#endif
+
+// Adds the sun disc, if any, with a slightly softened edge.
+fn add_sun_disc(sky: vec3<f32>, ray_direction: vec3<f32>) -> vec3<f32> {
+    if uniforms.sun_color.a <= 0.0 {
+        return sky;
+    }
+    let angle = acos(clamp(dot(ray_direction, uniforms.sun_direction.xyz), -1.0, 1.0));
+    let radius = acos(uniforms.sun_direction.w);
+    let sun = 1.0 - smoothstep(radius * (1.0 - SUN_EDGE_WIDTH), radius, angle);
+    return sky + uniforms.sun_color.rgb * sun;
+}
+
+// Fades everything below the horizon towards the horizon fade color, with a short gradient
+// above it. An alpha of zero disables the fade.
+fn apply_horizon_fade(sky: vec3<f32>, ray_direction: vec3<f32>) -> vec3<f32> {
+    let fade = (1.0 - smoothstep(0.0, HORIZON_FADE_HEIGHT, ray_direction.y)) * uniforms.horizon_fade.a;
+    return mix(sky, uniforms.horizon_fade.rgb, fade);
+}
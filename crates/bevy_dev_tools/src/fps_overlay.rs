@@ -3,7 +3,7 @@
 use bevy_app::{Plugin, Startup, Update};
 use bevy_asset::Handle;
 use bevy_color::Color;
-use bevy_diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy_diagnostic::{DiagnosticPath, DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use bevy_ecs::{
     component::Component,
     query::With,
@@ -14,7 +14,7 @@ use bevy_hierarchy::BuildChildren;
 use bevy_text::{Font, Text, TextSection, TextStyle};
 use bevy_ui::{
     node_bundles::{NodeBundle, TextBundle},
-    PositionType, Style, ZIndex,
+    PositionType, Style, Val, ZIndex,
 };
 use bevy_utils::default;
 
@@ -47,9 +47,10 @@ impl Plugin for FpsOverlayPlugin {
             .add_systems(
                 Update,
                 (
-                    customize_text.run_if(resource_changed::<FpsOverlayConfig>),
+                    customize_overlay.run_if(resource_changed::<FpsOverlayConfig>),
                     update_text,
-                ),
+                )
+                    .chain(),
             );
     }
 }
@@ -59,6 +60,12 @@ impl Plugin for FpsOverlayPlugin {
 pub struct FpsOverlayConfig {
     /// Configuration of text in the overlay.
     pub text_config: TextStyle,
+    /// The corner of the window the overlay is displayed in.
+    pub position: FpsOverlayPosition,
+    /// Whether to show the smoothed frames per second.
+    pub show_fps: bool,
+    /// Whether to show the smoothed frame time, in milliseconds.
+    pub show_frame_time: bool,
 }
 
 impl Default for FpsOverlayConfig {
@@ -69,53 +76,121 @@ impl Default for FpsOverlayConfig {
                 font_size: 32.0,
                 color: Color::WHITE,
             },
+            position: FpsOverlayPosition::TopLeft,
+            show_fps: true,
+            show_frame_time: false,
         }
     }
 }
 
+/// The corner of the window the FPS overlay is displayed in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FpsOverlayPosition {
+    /// The top left corner of the window.
+    #[default]
+    TopLeft,
+    /// The top right corner of the window.
+    TopRight,
+    /// The bottom left corner of the window.
+    BottomLeft,
+    /// The bottom right corner of the window.
+    BottomRight,
+}
+
+impl FpsOverlayConfig {
+    /// The diagnostics shown in the overlay, along with their label and the suffix appended
+    /// to their value.
+    fn shown_diagnostics(&self) -> Vec<(&'static str, DiagnosticPath, &'static str)> {
+        let mut diagnostics = Vec::new();
+        if self.show_fps {
+            diagnostics.push(("FPS: ", FrameTimeDiagnosticsPlugin::FPS, ""));
+        }
+        if self.show_frame_time {
+            diagnostics.push((
+                "Frame time: ",
+                FrameTimeDiagnosticsPlugin::FRAME_TIME,
+                " ms",
+            ));
+        }
+        diagnostics
+    }
+}
+
+#[derive(Component)]
+struct FpsOverlayRoot;
+
 #[derive(Component)]
 struct FpsText;
 
-fn setup(mut commands: Commands, overlay_config: Res<FpsOverlayConfig>) {
+fn setup(mut commands: Commands) {
     commands
-        .spawn(NodeBundle {
-            style: Style {
-                // We need to make sure the overlay doesn't affect the position of other UI nodes
-                position_type: PositionType::Absolute,
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    // We need to make sure the overlay doesn't affect the position of other UI nodes
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                // Render overlay on top of everything
+                z_index: ZIndex::Global(FPS_OVERLAY_ZINDEX),
                 ..default()
             },
-            // Render overlay on top of everything
-            z_index: ZIndex::Global(FPS_OVERLAY_ZINDEX),
-            ..default()
-        })
+            FpsOverlayRoot,
+        ))
         .with_children(|c| {
-            c.spawn((
-                TextBundle::from_sections([
-                    TextSection::new("FPS: ", overlay_config.text_config.clone()),
-                    TextSection::from_style(overlay_config.text_config.clone()),
-                ]),
-                FpsText,
-            ));
+            // The sections are filled in by `customize_overlay`, which runs when the config is
+            // first inserted.
+            c.spawn((TextBundle::default(), FpsText));
         });
 }
 
-fn update_text(diagnostic: Res<DiagnosticsStore>, mut query: Query<&mut Text, With<FpsText>>) {
+fn update_text(
+    diagnostic: Res<DiagnosticsStore>,
+    overlay_config: Res<FpsOverlayConfig>,
+    mut query: Query<&mut Text, With<FpsText>>,
+) {
     for mut text in &mut query {
-        if let Some(fps) = diagnostic.get(&FrameTimeDiagnosticsPlugin::FPS) {
-            if let Some(value) = fps.smoothed() {
-                text.sections[1].value = format!("{value:.2}");
+        for (i, (_, path, suffix)) in overlay_config.shown_diagnostics().into_iter().enumerate() {
+            let Some(value) = diagnostic.get(&path).and_then(|d| d.smoothed()) else {
+                continue;
+            };
+            if let Some(section) = text.sections.get_mut(i * 2 + 1) {
+                section.value = format!("{value:.2}{suffix}");
             }
         }
     }
 }
 
-fn customize_text(
+fn customize_overlay(
     overlay_config: Res<FpsOverlayConfig>,
-    mut query: Query<&mut Text, With<FpsText>>,
+    mut roots: Query<&mut Style, With<FpsOverlayRoot>>,
+    mut texts: Query<&mut Text, With<FpsText>>,
 ) {
-    for mut text in &mut query {
-        for section in text.sections.iter_mut() {
-            section.style = overlay_config.text_config.clone();
+    for mut style in &mut roots {
+        let (top, bottom, left, right) = match overlay_config.position {
+            FpsOverlayPosition::TopLeft => (Val::Px(0.0), Val::Auto, Val::Px(0.0), Val::Auto),
+            FpsOverlayPosition::TopRight => (Val::Px(0.0), Val::Auto, Val::Auto, Val::Px(0.0)),
+            FpsOverlayPosition::BottomLeft => (Val::Auto, Val::Px(0.0), Val::Px(0.0), Val::Auto),
+            FpsOverlayPosition::BottomRight => (Val::Auto, Val::Px(0.0), Val::Auto, Val::Px(0.0)),
+        };
+        style.top = top;
+        style.bottom = bottom;
+        style.left = left;
+        style.right = right;
+    }
+
+    for mut text in &mut texts {
+        text.sections.clear();
+        for (i, (label, _, _)) in overlay_config.shown_diagnostics().into_iter().enumerate() {
+            let label = if i == 0 {
+                label.to_string()
+            } else {
+                format!("\n{label}")
+            };
+            text.sections.extend([
+                TextSection::new(label, overlay_config.text_config.clone()),
+                TextSection::from_style(overlay_config.text_config.clone()),
+            ]);
         }
     }
 }
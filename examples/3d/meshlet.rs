@@ -4,6 +4,8 @@
 
 #[path = "../helpers/camera_controller.rs"]
 mod camera_controller;
+#[path = "../helpers/hotkeys.rs"]
+mod hotkeys;
 
 use bevy::{
     pbr::{
@@ -14,6 +16,7 @@ use bevy::{
     render::render_resource::AsBindGroup,
 };
 use camera_controller::{CameraController, CameraControllerPlugin};
+use hotkeys::HotkeysPlugin;
 use std::{f32::consts::PI, path::Path, process::ExitCode};
 
 const ASSET_URL: &str = "https://raw.githubusercontent.com/JMS55/bevy_meshlet_asset/bd869887bc5c9c6e74e353f657d342bef84bacd8/bunny.meshlet_mesh";
@@ -30,6 +33,7 @@ fn main() -> ExitCode {
             DefaultPlugins,
             MeshletPlugin,
             MaterialPlugin::<MeshletDebugMaterial>::default(),
+            HotkeysPlugin,
             CameraControllerPlugin,
         ))
         .add_systems(Startup, setup)
@@ -6,7 +6,6 @@
 //!
 //! Reflection probes don't work on WebGL 2 or WebGPU.
 
-use bevy::core_pipeline::Skybox;
 use bevy::prelude::*;
 
 use std::{
@@ -187,6 +186,7 @@ fn add_environment_map_to_camera(
             .insert(Skybox {
                 image: cubemaps.skybox.clone(),
                 brightness: 5000.0,
+                ..default()
             });
     }
 }
@@ -2,14 +2,17 @@
 
 #[path = "../helpers/camera_controller.rs"]
 mod camera_controller;
+#[path = "../helpers/hotkeys.rs"]
+mod hotkeys;
 
 use bevy::{pbr::ShadowFilteringMethod, prelude::*};
 use camera_controller::{CameraController, CameraControllerPlugin};
+use hotkeys::HotkeysPlugin;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(CameraControllerPlugin)
+        .add_plugins((HotkeysPlugin, CameraControllerPlugin))
         .add_systems(Startup, setup)
         .add_systems(
             Update,
@@ -2,10 +2,11 @@
 
 #[path = "../helpers/camera_controller.rs"]
 mod camera_controller;
+#[path = "../helpers/hotkeys.rs"]
+mod hotkeys;
 
 use bevy::{
     asset::LoadState,
-    core_pipeline::Skybox,
     prelude::*,
     render::{
         render_resource::{TextureViewDescriptor, TextureViewDimension},
@@ -14,6 +15,7 @@ use bevy::{
     },
 };
 use camera_controller::{CameraController, CameraControllerPlugin};
+use hotkeys::HotkeysPlugin;
 use std::f32::consts::PI;
 
 const CUBEMAPS: &[(&str, CompressedImageFormats)] = &[
@@ -38,7 +40,7 @@ const CUBEMAPS: &[(&str, CompressedImageFormats)] = &[
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(CameraControllerPlugin)
+        .add_plugins((HotkeysPlugin, CameraControllerPlugin))
         .add_systems(Startup, setup)
         .add_systems(
             Update,
@@ -78,10 +80,11 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
             ..default()
         },
         CameraController::default(),
-        Skybox {
-            image: skybox_handle.clone(),
-            brightness: 1000.0,
-        },
+        Skybox::builder()
+            .image(skybox_handle.clone())
+            .brightness(1000.0)
+            .build()
+            .expect("the skybox settings should be valid"),
     ));
 
     // ambient light
@@ -50,6 +50,12 @@ fn setup(
         ..default()
     });
 
+    // Each camera gets its own skybox, since a skybox only draws for the camera it's added to
+    let skyboxes = [
+        "environment_maps/pisa_specular_rgb9e5_zstd.ktx2",
+        "environment_maps/cubes_reflection_probe_specular_rgb9e5_zstd.ktx2",
+    ];
+
     // Cameras and their dedicated UI
     for (index, (camera_name, camera_pos)) in [
         ("Player 1", Vec3::new(0.0, 200.0, -150.0)),
@@ -66,6 +72,8 @@ fn setup(
                     transform: Transform::from_translation(*camera_pos)
                         .looking_at(Vec3::ZERO, Vec3::Y),
                     camera: Camera {
+                        // The skyboxes are HDR images, which would clip without an HDR camera
+                        hdr: true,
                         // Renders cameras with different priorities to prevent ambiguities
                         order: index as isize,
                         // Don't clear after the first camera because the first camera already cleared the entire window
@@ -81,6 +89,11 @@ fn setup(
                 CameraPosition {
                     pos: UVec2::new((index % 2) as u32, (index / 2) as u32),
                 },
+                Skybox {
+                    image: asset_server.load(skyboxes[index % skyboxes.len()]),
+                    brightness: 1000.0,
+                    ..default()
+                },
             ))
             .id();
 
@@ -4,7 +4,7 @@ use std::ops::Range;
 
 use bevy::{
     color::palettes::css::{BLACK, WHITE},
-    core_pipeline::{fxaa::Fxaa, Skybox},
+    core_pipeline::fxaa::Fxaa,
     input::mouse::MouseWheel,
     math::{vec3, vec4},
     pbr::{
@@ -243,6 +243,7 @@ fn spawn_camera(commands: &mut Commands, asset_server: &AssetServer) {
         .insert(Skybox {
             image: asset_server.load("environment_maps/pisa_specular_rgb9e5_zstd.ktx2"),
             brightness: 5000.0,
+            ..default()
         })
         .insert(ScreenSpaceReflectionsBundle::default())
         .insert(Fxaa::default());
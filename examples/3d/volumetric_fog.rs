@@ -1,7 +1,7 @@
 //! Demonstrates volumetric fog and lighting (light shafts or god rays).
 
 use bevy::{
-    core_pipeline::{bloom::BloomSettings, tonemapping::Tonemapping, Skybox},
+    core_pipeline::{bloom::BloomSettings, tonemapping::Tonemapping},
     math::vec3,
     pbr::{VolumetricFogSettings, VolumetricLight},
     prelude::*,
@@ -52,6 +52,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         .insert(Skybox {
             image: asset_server.load("environment_maps/pisa_specular_rgb9e5_zstd.ktx2"),
             brightness: 1000.0,
+            ..default()
         })
         .insert(VolumetricFogSettings {
             // This value is explicitly set to 0 since we have no environment map light
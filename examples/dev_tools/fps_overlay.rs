@@ -1,7 +1,7 @@
 //! Showcase how to use and configure FPS overlay.
 
 use bevy::{
-    dev_tools::fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin},
+    dev_tools::fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin, FpsOverlayPosition},
     prelude::*,
 };
 
@@ -19,6 +19,9 @@ fn main() {
                         // If we want, we can use a custom font
                         font: default(),
                     },
+                    // We can choose which corner of the window the overlay is shown in
+                    position: FpsOverlayPosition::TopLeft,
+                    ..default()
                 },
             },
         ))
@@ -47,7 +50,9 @@ fn setup(mut commands: Commands) {
             c.spawn(TextBundle::from_section(
                 concat!(
                     "Press 1 to change color of the overlay.\n",
-                    "Press 2 to change size of the overlay."
+                    "Press 2 to change size of the overlay.\n",
+                    "Press 3 to toggle the frame time.\n",
+                    "Press 4 to move the overlay to another corner."
                 ),
                 TextStyle {
                     font_size: 25.0,
@@ -65,4 +70,15 @@ fn customize_config(input: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<FpsOve
     if input.just_pressed(KeyCode::Digit2) {
         overlay.text_config.font_size -= 2.0;
     }
+    if input.just_pressed(KeyCode::Digit3) {
+        overlay.show_frame_time = !overlay.show_frame_time;
+    }
+    if input.just_pressed(KeyCode::Digit4) {
+        overlay.position = match overlay.position {
+            FpsOverlayPosition::TopLeft => FpsOverlayPosition::TopRight,
+            FpsOverlayPosition::TopRight => FpsOverlayPosition::BottomRight,
+            FpsOverlayPosition::BottomRight => FpsOverlayPosition::BottomLeft,
+            FpsOverlayPosition::BottomLeft => FpsOverlayPosition::TopLeft,
+        };
+    }
 }
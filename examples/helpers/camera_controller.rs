@@ -2,17 +2,57 @@
 //! To use in your own application:
 //! - Copy the code for the [`CameraControllerPlugin`] and add the plugin to your App.
 //! - Attach the [`CameraController`] component to an entity with a [`Camera3dBundle`].
+//! - The keys are registered in the [`HotkeyRegistry`], so the `hotkeys` helper needs to be
+//!   included too. Rebind them with [`HotkeyRegistry::rebind`].
 
 use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
 use bevy::window::CursorGrabMode;
-use std::{f32::consts::*, fmt};
+use std::f32::consts::*;
+
+use super::hotkeys::{HotkeyRegistry, RegisterHotkey};
+
+/// Flies forward.
+const FORWARD: &str = "camera_forward";
+/// Flies backwards.
+const BACK: &str = "camera_back";
+/// Flies sideways to the left.
+const LEFT: &str = "camera_left";
+/// Flies sideways to the right.
+const RIGHT: &str = "camera_right";
+/// Flies up.
+const UP: &str = "camera_up";
+/// Flies down.
+const DOWN: &str = "camera_down";
+/// Flies faster while held.
+const RUN: &str = "camera_run";
+/// Toggles cursor grab.
+const TOGGLE_CURSOR_GRAB: &str = "camera_toggle_cursor_grab";
+/// Snaps to the front view.
+const SNAP_FRONT: &str = "camera_snap_front";
+/// Snaps to the right view.
+const SNAP_RIGHT: &str = "camera_snap_right";
+/// Snaps to the top view.
+const SNAP_TOP: &str = "camera_snap_top";
+/// Held to snap to the opposite view.
+const SNAP_OPPOSITE: &str = "camera_snap_opposite";
+/// Switches between a perspective and an orthographic projection.
+const TOGGLE_ORTHOGRAPHIC: &str = "camera_toggle_orthographic";
+/// Moves the camera back to its home transform.
+const RESET: &str = "camera_reset";
 
 pub struct CameraControllerPlugin;
 
 impl Plugin for CameraControllerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, run_camera_controller);
+        for (action, default_key) in CameraController::default().hotkeys() {
+            app.register_hotkey(action, default_key);
+        }
+        app.add_event::<CameraControllerCommand>()
+            .add_event::<CameraIdle>()
+            .add_event::<CameraActive>()
+            .add_systems(Update, run_camera_controller);
     }
 }
 
@@ -21,6 +61,11 @@ impl Plugin for CameraControllerPlugin {
 /// it because it felt nice.
 pub const RADIANS_PER_DOT: f32 = 1.0 / 180.0;
 
+/// A freecam controller.
+///
+/// The `key_*` fields are the default keys of the controller's actions in the
+/// [`HotkeyRegistry`], which is where the controller reads its keys from. A controller spawned
+/// with a different key rebinds that action once it initializes.
 #[derive(Component)]
 pub struct CameraController {
     pub enabled: bool,
@@ -35,6 +80,24 @@ pub struct CameraController {
     pub key_run: KeyCode,
     pub mouse_key_cursor_grab: MouseButton,
     pub keyboard_key_toggle_cursor_grab: KeyCode,
+    /// Snaps the camera to look along -Z, or +Z while [`Self::key_snap_opposite`] is held.
+    pub key_snap_front: KeyCode,
+    /// Snaps the camera to look along -X, or +X while [`Self::key_snap_opposite`] is held.
+    pub key_snap_right: KeyCode,
+    /// Snaps the camera to look along -Y, or +Y while [`Self::key_snap_opposite`] is held.
+    pub key_snap_top: KeyCode,
+    /// Held to snap to the opposite direction.
+    pub key_snap_opposite: KeyCode,
+    /// Switches between a perspective and an orthographic projection.
+    pub key_toggle_orthographic: KeyCode,
+    /// Moves the camera back to its [`Self::home`] transform.
+    pub key_reset: KeyCode,
+    /// Double-clicking this button also moves the camera back to [`Self::home`].
+    ///
+    /// `None` disables resetting with the mouse.
+    pub mouse_key_reset: Option<MouseButton>,
+    /// The longest time, in seconds, between the two clicks of a double-click.
+    pub double_click_time: f32,
     pub walk_speed: f32,
     pub run_speed: f32,
     pub scroll_factor: f32,
@@ -42,6 +105,40 @@ pub struct CameraController {
     pub pitch: f32,
     pub yaw: f32,
     pub velocity: Vec3,
+    /// How long, in seconds, mouse look takes to catch up with the mouse, smoothing out jitter.
+    ///
+    /// The mouse movement is exponentially smoothed with this time constant, which adds a
+    /// little input latency. `0.0` disables smoothing, so the camera follows the mouse 1:1.
+    pub look_smoothing: f32,
+    /// Mouse movements shorter than this many dots in a frame are ignored.
+    pub look_deadzone: f32,
+    /// The smoothed mouse movement applied to the camera last frame.
+    pub look_delta: Vec2,
+    /// The distance to the point the camera orbits around when snapping to an axis, which is
+    /// straight ahead of the camera. This also sizes the orthographic view to roughly match
+    /// the perspective one at that distance.
+    pub snap_distance: f32,
+    /// How long, in seconds, the camera takes to snap to an axis.
+    pub snap_duration: f32,
+    /// The perspective projection to restore when leaving the orthographic projection.
+    pub perspective: Option<PerspectiveProjection>,
+    /// The transform the camera is reset to. When `None`, it is set to the camera's transform
+    /// the first time the controller runs.
+    pub home: Option<Transform>,
+    /// How long, in seconds, the camera takes to move back to [`Self::home`]. `0.0` teleports
+    /// it there instead.
+    pub reset_duration: f32,
+    /// The smooth move started by [`CameraControllerCommand::MoveTo`], if one is in progress.
+    pub smooth_move: Option<SmoothMove>,
+    /// How long, in seconds, the user has to leave the camera alone before [`CameraIdle`] is
+    /// sent. [`CameraActive`] is sent once they touch the controls again.
+    ///
+    /// `None` disables idle detection.
+    pub idle_timeout: Option<f32>,
+    /// How long, in seconds, the camera has gone without user input.
+    pub idle_time: f32,
+    /// Whether [`CameraIdle`] has been sent since the user last touched the controls.
+    pub idle: bool,
 }
 
 impl Default for CameraController {
@@ -59,6 +156,14 @@ impl Default for CameraController {
             key_run: KeyCode::ShiftLeft,
             mouse_key_cursor_grab: MouseButton::Left,
             keyboard_key_toggle_cursor_grab: KeyCode::KeyM,
+            key_snap_front: KeyCode::Numpad1,
+            key_snap_right: KeyCode::Numpad3,
+            key_snap_top: KeyCode::Numpad7,
+            key_snap_opposite: KeyCode::ControlLeft,
+            key_toggle_orthographic: KeyCode::Numpad5,
+            key_reset: KeyCode::Home,
+            mouse_key_reset: None,
+            double_click_time: 0.3,
             walk_speed: 5.0,
             run_speed: 15.0,
             scroll_factor: 0.1,
@@ -66,35 +171,188 @@ impl Default for CameraController {
             pitch: 0.0,
             yaw: 0.0,
             velocity: Vec3::ZERO,
+            look_smoothing: 0.0,
+            look_deadzone: 0.0,
+            look_delta: Vec2::ZERO,
+            snap_distance: 10.0,
+            snap_duration: 0.25,
+            perspective: None,
+            home: None,
+            reset_duration: 0.5,
+            smooth_move: None,
+            idle_timeout: None,
+            idle_time: 0.0,
+            idle: false,
         }
     }
 }
 
-impl fmt::Display for CameraController {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
+/// Sent when the user hasn't moved the camera for [`CameraController::idle_timeout`] seconds.
+#[derive(Event)]
+pub struct CameraIdle;
+
+/// Sent when the user moves the camera again after a [`CameraIdle`].
+#[derive(Event)]
+pub struct CameraActive;
+
+/// Commands that drive the [`CameraController`] from code, e.g. for scripted shots.
+#[derive(Event, Clone, Copy)]
+// Not every example using this helper sends commands.
+#[allow(dead_code)]
+pub enum CameraControllerCommand {
+    /// Moves the camera to `transform`.
+    ///
+    /// With a `duration` (in seconds) the camera eases there, ignoring manual input until it
+    /// arrives. Pressing a movement key interrupts the move. Without one, it teleports.
+    /// Moves also play out while the controller is disabled, but can't be interrupted then.
+    MoveTo {
+        transform: Transform,
+        duration: Option<f32>,
+    },
+    /// Sets the transform the camera is reset to, see [`CameraController::home`].
+    SetHome { transform: Transform },
+}
+
+/// An eased transition between two transforms.
+#[derive(Clone, Copy)]
+pub struct SmoothMove {
+    pub start: Transform,
+    pub target: Transform,
+    pub duration: f32,
+    pub elapsed: f32,
+}
+
+impl SmoothMove {
+    /// Advances the move by `dt` seconds, returning the interpolated transform and whether
+    /// the move has finished.
+    fn advance(&mut self, dt: f32) -> (Transform, bool) {
+        self.elapsed += dt;
+        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        // Smoothstep, so the camera eases in and out.
+        let t = t * t * (3.0 - 2.0 * t);
+        let transform = interpolate_transform(&self.start, &self.target, t);
+        (transform, self.elapsed >= self.duration)
+    }
+}
+
+/// Interpolates from `start` to `target`, where a `t` of zero is `start` and one is `target`.
+pub fn interpolate_transform(start: &Transform, target: &Transform, t: f32) -> Transform {
+    Transform {
+        translation: start.translation.lerp(target.translation, t),
+        rotation: start.rotation.slerp(target.rotation, t),
+        scale: start.scale.lerp(target.scale, t),
+    }
+}
+
+impl CameraController {
+    /// Updates `yaw` and `pitch` to match the rotation of `transform`, so mouse look continues
+    /// from wherever the camera was placed.
+    fn sync_rotation(&mut self, transform: &Transform) {
+        let (yaw, pitch, _roll) = transform.rotation.to_euler(EulerRot::YXZ);
+        self.yaw = yaw;
+        self.pitch = pitch;
+    }
+
+    /// Moves `transform` to `target`, easing there over `duration` seconds, or teleporting if
+    /// `duration` isn't positive.
+    fn move_to(&mut self, transform: &mut Transform, target: Transform, duration: f32) {
+        if duration > 0.0 {
+            self.smooth_move = Some(SmoothMove {
+                start: *transform,
+                target,
+                duration,
+                elapsed: 0.0,
+            });
+        } else {
+            *transform = target;
+            self.smooth_move = None;
+            self.sync_rotation(transform);
+        }
+    }
+
+    /// The controller's hotkey actions, along with this controller's keys for them.
+    fn hotkeys(&self) -> [(&'static str, KeyCode); 14] {
+        [
+            (FORWARD, self.key_forward),
+            (BACK, self.key_back),
+            (LEFT, self.key_left),
+            (RIGHT, self.key_right),
+            (UP, self.key_up),
+            (DOWN, self.key_down),
+            (RUN, self.key_run),
+            (TOGGLE_CURSOR_GRAB, self.keyboard_key_toggle_cursor_grab),
+            (SNAP_FRONT, self.key_snap_front),
+            (SNAP_RIGHT, self.key_snap_right),
+            (SNAP_TOP, self.key_snap_top),
+            (SNAP_OPPOSITE, self.key_snap_opposite),
+            (TOGGLE_ORTHOGRAPHIC, self.key_toggle_orthographic),
+            (RESET, self.key_reset),
+        ]
+    }
+
+    /// Rebinds the actions this controller has a different key for than the default one.
+    fn rebind_hotkeys(&self, hotkeys: &mut HotkeyRegistry) {
+        let defaults = Self::default().hotkeys();
+        for ((action, key), (_, default_key)) in self.hotkeys().into_iter().zip(defaults) {
+            if key != default_key {
+                hotkeys.rebind(action, key);
+            }
+        }
+    }
+
+    /// The controls, with the keys currently bound in `hotkeys`.
+    fn help_text(&self, hotkeys: &HotkeyRegistry) -> String {
+        let key = |action| {
+            hotkeys
+                .key(action)
+                .map_or_else(|| "Unbound".to_string(), |key| format!("{key:?}"))
+        };
+        format!(
             "
 Freecam Controls:
     Mouse\t- Move camera orientation
     Scroll\t- Adjust movement speed
     {:?}\t- Hold to grab cursor
-    {:?}\t- Toggle cursor grab
-    {:?} & {:?}\t- Fly forward & backwards
-    {:?} & {:?}\t- Fly sideways left & right
-    {:?} & {:?}\t- Fly up & down
-    {:?}\t- Fly faster while held",
+    {}\t- Toggle cursor grab
+    {} & {}\t- Fly forward & backwards
+    {} & {}\t- Fly sideways left & right
+    {} & {}\t- Fly up & down
+    {}\t- Fly faster while held
+    {}, {} & {}\t- Snap to front, right & top view
+    {}\t- Hold to snap to back, left & bottom view
+    {}\t- Toggle orthographic projection
+    {}\t- Reset to the home view{}",
             self.mouse_key_cursor_grab,
-            self.keyboard_key_toggle_cursor_grab,
-            self.key_forward,
-            self.key_back,
-            self.key_left,
-            self.key_right,
-            self.key_up,
-            self.key_down,
-            self.key_run,
+            key(TOGGLE_CURSOR_GRAB),
+            key(FORWARD),
+            key(BACK),
+            key(LEFT),
+            key(RIGHT),
+            key(UP),
+            key(DOWN),
+            key(RUN),
+            key(SNAP_FRONT),
+            key(SNAP_RIGHT),
+            key(SNAP_TOP),
+            key(SNAP_OPPOSITE),
+            key(TOGGLE_ORTHOGRAPHIC),
+            key(RESET),
+            match self.mouse_key_reset {
+                Some(button) => format!("\n    Double-click {button:?}\t- Reset to the home view"),
+                None => String::new(),
+            },
         )
     }
+
+    /// Advances the smooth move in progress, if any, by `dt` seconds.
+    fn advance_smooth_move(&mut self, transform: &mut Transform, dt: f32) {
+        if let Some(mut smooth_move) = self.smooth_move {
+            let (new_transform, finished) = smooth_move.advance(dt);
+            *transform = new_transform;
+            self.smooth_move = (!finished).then_some(smooth_move);
+            self.sync_rotation(transform);
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -103,23 +361,53 @@ fn run_camera_controller(
     mut windows: Query<&mut Window>,
     mut mouse_events: EventReader<MouseMotion>,
     mut scroll_events: EventReader<MouseWheel>,
+    mut controller_commands: EventReader<CameraControllerCommand>,
+    mut idle_events: EventWriter<CameraIdle>,
+    mut active_events: EventWriter<CameraActive>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     key_input: Res<ButtonInput<KeyCode>>,
+    mut hotkeys: ResMut<HotkeyRegistry>,
     mut toggle_cursor_grab: Local<bool>,
     mut mouse_cursor_grab: Local<bool>,
-    mut query: Query<(&mut Transform, &mut CameraController), With<Camera>>,
+    mut last_reset_click: Local<Option<f32>>,
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut CameraController,
+            Option<&mut Projection>,
+        ),
+        With<Camera>,
+    >,
 ) {
     let dt = time.delta_seconds();
 
-    if let Ok((mut transform, mut controller)) = query.get_single_mut() {
+    if let Ok((mut transform, mut controller, projection)) = query.get_single_mut() {
         if !controller.initialized {
-            let (yaw, pitch, _roll) = transform.rotation.to_euler(EulerRot::YXZ);
-            controller.yaw = yaw;
-            controller.pitch = pitch;
+            controller.sync_rotation(&transform);
+            controller.rebind_hotkeys(&mut hotkeys);
+            if controller.home.is_none() {
+                controller.home = Some(*transform);
+            }
             controller.initialized = true;
-            info!("{}", *controller);
+            info!("{}", controller.help_text(&hotkeys));
+        }
+        let pressed = |action| hotkeys.key(action).is_some_and(|key| key_input.pressed(key));
+        for command in controller_commands.read() {
+            match *command {
+                CameraControllerCommand::MoveTo {
+                    transform: target,
+                    duration,
+                } => controller.move_to(&mut transform, target, duration.unwrap_or(0.0)),
+                CameraControllerCommand::SetHome { transform } => {
+                    controller.home = Some(transform);
+                    continue;
+                }
+            }
+            controller.velocity = Vec3::ZERO;
+            controller.look_delta = Vec2::ZERO;
         }
         if !controller.enabled {
+            controller.advance_smooth_move(&mut transform, dt);
             mouse_events.clear();
             return;
         }
@@ -137,27 +425,109 @@ fn run_camera_controller(
 
         // Handle key input
         let mut axis_input = Vec3::ZERO;
-        if key_input.pressed(controller.key_forward) {
+        if pressed(FORWARD) {
             axis_input.z += 1.0;
         }
-        if key_input.pressed(controller.key_back) {
+        if pressed(BACK) {
             axis_input.z -= 1.0;
         }
-        if key_input.pressed(controller.key_right) {
+        if pressed(RIGHT) {
             axis_input.x += 1.0;
         }
-        if key_input.pressed(controller.key_left) {
+        if pressed(LEFT) {
             axis_input.x -= 1.0;
         }
-        if key_input.pressed(controller.key_up) {
+        if pressed(UP) {
             axis_input.y += 1.0;
         }
-        if key_input.pressed(controller.key_down) {
+        if pressed(DOWN) {
             axis_input.y -= 1.0;
         }
 
+        // Snap to look along an axis, orbiting the point straight ahead of the camera
+        let snap_direction = if hotkeys.just_pressed(SNAP_FRONT, &key_input) {
+            Some(Dir3::NEG_Z)
+        } else if hotkeys.just_pressed(SNAP_RIGHT, &key_input) {
+            Some(Dir3::NEG_X)
+        } else if hotkeys.just_pressed(SNAP_TOP, &key_input) {
+            Some(Dir3::NEG_Y)
+        } else {
+            None
+        };
+        if let Some(mut direction) = snap_direction {
+            if pressed(SNAP_OPPOSITE) {
+                direction = -direction;
+            }
+            let pivot = transform.translation + transform.forward() * controller.snap_distance;
+            // Y can't be up when looking straight up or down, so keep -Z at the top instead
+            let up = if direction.y == 0.0 {
+                Dir3::Y
+            } else {
+                Dir3::NEG_Z
+            };
+            let target = Transform::from_translation(pivot - direction * controller.snap_distance)
+                .looking_to(direction, up);
+            controller.velocity = Vec3::ZERO;
+            let duration = controller.snap_duration;
+            controller.move_to(&mut transform, target, duration);
+        }
+
+        // Reset to the home transform with the reset key, or by double-clicking
+        let mut reset = hotkeys.just_pressed(RESET, &key_input);
+        if let Some(button) = controller.mouse_key_reset {
+            if mouse_button_input.just_pressed(button) {
+                let now = time.elapsed_seconds();
+                match *last_reset_click {
+                    Some(last) if now - last <= controller.double_click_time => {
+                        reset = true;
+                        // Don't count a third click as another double-click
+                        *last_reset_click = None;
+                    }
+                    _ => *last_reset_click = Some(now),
+                }
+            }
+        }
+        if reset {
+            if let Some(home) = controller.home {
+                controller.velocity = Vec3::ZERO;
+                controller.look_delta = Vec2::ZERO;
+                let duration = controller.reset_duration;
+                controller.move_to(&mut transform, home, duration);
+            }
+        }
+
+        if hotkeys.just_pressed(TOGGLE_ORTHOGRAPHIC, &key_input) {
+            if let Some(mut projection) = projection {
+                match &*projection {
+                    Projection::Perspective(perspective) => {
+                        let height = 2.0 * controller.snap_distance * (perspective.fov / 2.0).tan();
+                        controller.perspective = Some(perspective.clone());
+                        *projection = Projection::Orthographic(OrthographicProjection {
+                            scaling_mode: ScalingMode::FixedVertical(height),
+                            ..default()
+                        });
+                    }
+                    Projection::Orthographic(_) => {
+                        let perspective = controller.perspective.take().unwrap_or_default();
+                        *projection = Projection::Perspective(perspective);
+                    }
+                }
+            }
+        }
+
+        // A smooth move ignores manual input, unless the user tries to move the camera.
+        if controller.smooth_move.is_some() {
+            if axis_input == Vec3::ZERO {
+                controller.advance_smooth_move(&mut transform, dt);
+                mouse_events.clear();
+                return;
+            }
+            controller.smooth_move = None;
+            controller.sync_rotation(&transform);
+        }
+
         let mut cursor_grab_change = false;
-        if key_input.just_pressed(controller.keyboard_key_toggle_cursor_grab) {
+        if hotkeys.just_pressed(TOGGLE_CURSOR_GRAB, &key_input) {
             *toggle_cursor_grab = !*toggle_cursor_grab;
             cursor_grab_change = true;
         }
@@ -173,7 +543,7 @@ fn run_camera_controller(
 
         // Apply movement update
         if axis_input != Vec3::ZERO {
-            let max_speed = if key_input.pressed(controller.key_run) {
+            let max_speed = if pressed(RUN) {
                 controller.run_speed
             } else {
                 controller.walk_speed
@@ -220,15 +590,48 @@ fn run_camera_controller(
         } else {
             mouse_events.clear();
         }
+        if mouse_delta.length() <= controller.look_deadzone {
+            mouse_delta = Vec2::ZERO;
+        }
+
+        // Exponentially smooth the mouse movement. Without smoothing this is just `mouse_delta`.
+        let look_blend = if controller.look_smoothing > 0.0 {
+            1.0 - (-dt / controller.look_smoothing).exp()
+        } else {
+            1.0
+        };
+        controller.look_delta = controller.look_delta.lerp(mouse_delta, look_blend);
+        if mouse_delta == Vec2::ZERO && controller.look_delta.length_squared() < 1e-6 {
+            controller.look_delta = Vec2::ZERO;
+        }
+        let look_delta = controller.look_delta;
 
-        if mouse_delta != Vec2::ZERO {
+        if look_delta != Vec2::ZERO {
             // Apply look update
             controller.pitch = (controller.pitch
-                - mouse_delta.y * RADIANS_PER_DOT * controller.sensitivity)
+                - look_delta.y * RADIANS_PER_DOT * controller.sensitivity)
                 .clamp(-PI / 2., PI / 2.);
-            controller.yaw -= mouse_delta.x * RADIANS_PER_DOT * controller.sensitivity;
+            controller.yaw -= look_delta.x * RADIANS_PER_DOT * controller.sensitivity;
             transform.rotation =
                 Quat::from_euler(EulerRot::ZYX, 0.0, controller.yaw, controller.pitch);
         }
+
+        // Detect whether the user has stopped moving the camera
+        if let Some(idle_timeout) = controller.idle_timeout {
+            let active = axis_input != Vec3::ZERO || mouse_delta != Vec2::ZERO || scroll != 0.0;
+            if active {
+                controller.idle_time = 0.0;
+                if controller.idle {
+                    controller.idle = false;
+                    active_events.send(CameraActive);
+                }
+            } else {
+                controller.idle_time += dt;
+                if !controller.idle && controller.idle_time >= idle_timeout {
+                    controller.idle = true;
+                    idle_events.send(CameraIdle);
+                }
+            }
+        }
     }
 }
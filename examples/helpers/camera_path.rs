@@ -0,0 +1,309 @@
+//! Records the path a [`CameraController`] camera takes and plays it back, e.g. for
+//! reproducible demos and benchmarks.
+//! To use in your own application:
+//! - Copy the code for the [`CameraPathPlugin`] and add the plugin to your App.
+//! - Attach the [`CameraPathRecorder`] component to the entity with the [`CameraController`].
+//! - This builds on the `camera_controller` helper, which needs to be included as well.
+//! - The keys are registered in the [`HotkeyRegistry`], so the `hotkeys` helper needs to be
+//!   included too. Rebind them with [`HotkeyRegistry::rebind`].
+
+use bevy::prelude::*;
+use bevy::reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    TypeRegistry,
+};
+use serde::de::DeserializeSeed;
+use std::{any::TypeId, error::Error};
+
+use super::camera_controller::{interpolate_transform, CameraController, CameraControllerCommand};
+use super::hotkeys::{HotkeyRegistry, RegisterHotkey};
+
+/// Starts recording a new path, replacing the current one, or stops recording.
+const RECORD: &str = "camera_path_record";
+/// Starts or stops playing back the current path.
+const PLAY: &str = "camera_path_play";
+/// Halves the playback speed.
+const SLOWER: &str = "camera_path_slower";
+/// Doubles the playback speed.
+const FASTER: &str = "camera_path_faster";
+/// Saves the current path to [`CameraPathRecorder::file`].
+const SAVE: &str = "camera_path_save";
+/// Loads the path saved in [`CameraPathRecorder::file`], replacing the current one.
+const LOAD: &str = "camera_path_load";
+
+pub struct CameraPathPlugin;
+
+impl Plugin for CameraPathPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraPath>()
+            .register_hotkey(RECORD, KeyCode::F5)
+            .register_hotkey(PLAY, KeyCode::F6)
+            .register_hotkey(SLOWER, KeyCode::BracketLeft)
+            .register_hotkey(FASTER, KeyCode::BracketRight)
+            .register_hotkey(SAVE, KeyCode::F7)
+            .register_hotkey(LOAD, KeyCode::F8)
+            .add_systems(Update, (log_camera_path_controls, run_camera_path_recorder));
+    }
+}
+
+/// A recorded camera path, which can be saved to and loaded from a RON file through reflection.
+#[derive(Reflect, Clone, Default, Debug)]
+pub struct CameraPath {
+    /// The keyframes, in chronological order.
+    pub keyframes: Vec<CameraKeyframe>,
+}
+
+/// The camera's transform at a point in a [`CameraPath`].
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct CameraKeyframe {
+    /// The time, in seconds, since the start of the path.
+    pub time: f32,
+    pub transform: Transform,
+}
+
+impl CameraPath {
+    /// How long, in seconds, the path takes to play back at normal speed.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    /// The camera's transform `time` seconds into the path, interpolated between the keyframes
+    /// on either side, or `None` if the path is empty.
+    pub fn sample(&self, time: f32) -> Option<Transform> {
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        let previous = next.checked_sub(1).map(|index| &self.keyframes[index]);
+        match (previous, self.keyframes.get(next)) {
+            (Some(previous), Some(next)) => {
+                let t = (time - previous.time) / (next.time - previous.time);
+                Some(interpolate_transform(
+                    &previous.transform,
+                    &next.transform,
+                    t,
+                ))
+            }
+            (Some(keyframe), None) | (None, Some(keyframe)) => Some(keyframe.transform),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Records the path of the [`CameraController`] on the same entity, and plays it back.
+///
+/// The controller ignores manual input during playback.
+#[derive(Component)]
+pub struct CameraPathRecorder {
+    /// The file paths are saved to and loaded from.
+    pub file: String,
+    /// The shortest time, in seconds, between two recorded keyframes.
+    pub record_interval: f32,
+    /// How fast the path is played back, where `1.0` is the speed it was recorded at.
+    pub speed: f32,
+    /// Whether playback starts over once it reaches the end of the path, instead of stopping.
+    pub looping: bool,
+    /// The path that was last recorded or loaded.
+    pub path: CameraPath,
+    pub state: CameraPathState,
+}
+
+impl Default for CameraPathRecorder {
+    fn default() -> Self {
+        Self {
+            file: "camera_path.ron".to_string(),
+            record_interval: 0.1,
+            speed: 1.0,
+            looping: true,
+            path: CameraPath::default(),
+            state: CameraPathState::Idle,
+        }
+    }
+}
+
+/// What a [`CameraPathRecorder`] is doing.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CameraPathState {
+    #[default]
+    Idle,
+    /// Recording a new path, `time` seconds after recording started.
+    Recording { time: f32 },
+    /// Playing back the path, `time` seconds into it.
+    Playing { time: f32 },
+}
+
+/// Logs the controls once a [`CameraPathRecorder`] is added.
+fn log_camera_path_controls(
+    hotkeys: Res<HotkeyRegistry>,
+    recorders: Query<&CameraPathRecorder, Added<CameraPathRecorder>>,
+) {
+    let key = |action| {
+        hotkeys
+            .key(action)
+            .map_or_else(|| "Unbound".to_string(), |key| format!("{key:?}"))
+    };
+    for recorder in &recorders {
+        info!(
+            "
+Camera Path Controls:
+    {}\t- Start & stop recording
+    {}\t- Start & stop playback
+    {} & {}\t- Halve & double playback speed
+    {} & {}\t- Save & load {}",
+            key(RECORD),
+            key(PLAY),
+            key(SLOWER),
+            key(FASTER),
+            key(SAVE),
+            key(LOAD),
+            recorder.file,
+        );
+    }
+}
+
+fn save_camera_path(
+    path: &CameraPath,
+    file: &str,
+    type_registry: &TypeRegistry,
+) -> Result<(), Box<dyn Error>> {
+    let serializer = TypedReflectSerializer::new(path, type_registry);
+    let ron = ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default())?;
+    std::fs::write(file, ron)?;
+    Ok(())
+}
+
+fn load_camera_path(
+    file: &str,
+    type_registry: &TypeRegistry,
+) -> Result<CameraPath, Box<dyn Error>> {
+    let ron = std::fs::read_to_string(file)?;
+    let registration = type_registry
+        .get(TypeId::of::<CameraPath>())
+        .ok_or("CameraPath is not registered")?;
+    let mut deserializer = ron::de::Deserializer::from_str(&ron)?;
+    let value = TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(&mut deserializer)?;
+    Ok(CameraPath::from_reflect(&*value).ok_or("the file doesn't contain a camera path")?)
+}
+
+fn run_camera_path_recorder(
+    time: Res<Time>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    hotkeys: Res<HotkeyRegistry>,
+    type_registry: Res<AppTypeRegistry>,
+    mut controller_commands: EventWriter<CameraControllerCommand>,
+    mut query: Query<(
+        &mut Transform,
+        &mut CameraController,
+        &mut CameraPathRecorder,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    let just_pressed = |action| hotkeys.just_pressed(action, &key_input);
+
+    for (mut transform, mut controller, mut recorder) in &mut query {
+        let recorder = &mut *recorder;
+        let playing = matches!(recorder.state, CameraPathState::Playing { .. });
+        // Hands the camera back to the controller, continuing from wherever playback left it.
+        let mut stop_playback = |controller: &mut CameraController, transform: &Transform| {
+            controller.enabled = true;
+            controller_commands.send(CameraControllerCommand::MoveTo {
+                transform: *transform,
+                duration: None,
+            });
+        };
+
+        if just_pressed(SLOWER) {
+            recorder.speed /= 2.0;
+            info!("Camera path playback speed: {}x", recorder.speed);
+        }
+        if just_pressed(FASTER) {
+            recorder.speed *= 2.0;
+            info!("Camera path playback speed: {}x", recorder.speed);
+        }
+
+        if just_pressed(SAVE) {
+            match save_camera_path(&recorder.path, &recorder.file, &type_registry.read()) {
+                Ok(()) => info!("Saved camera path to {}", recorder.file),
+                Err(error) => error!("Failed to save camera path to {}: {error}", recorder.file),
+            }
+        }
+        if just_pressed(LOAD) {
+            match load_camera_path(&recorder.file, &type_registry.read()) {
+                Ok(path) => {
+                    info!(
+                        "Loaded camera path from {} ({} keyframes)",
+                        recorder.file,
+                        path.keyframes.len()
+                    );
+                    recorder.path = path;
+                    if playing {
+                        stop_playback(&mut controller, &transform);
+                    }
+                    recorder.state = CameraPathState::Idle;
+                }
+                Err(error) => error!("Failed to load camera path from {}: {error}", recorder.file),
+            }
+        }
+
+        if just_pressed(RECORD) {
+            if let CameraPathState::Recording { time } = recorder.state {
+                info!(
+                    "Recorded {} camera keyframes over {time:.1}s",
+                    recorder.path.keyframes.len()
+                );
+                recorder.state = CameraPathState::Idle;
+            } else {
+                if playing {
+                    stop_playback(&mut controller, &transform);
+                }
+                recorder.path.keyframes = vec![CameraKeyframe {
+                    time: 0.0,
+                    transform: *transform,
+                }];
+                recorder.state = CameraPathState::Recording { time: 0.0 };
+                info!("Recording camera path");
+            }
+        } else if just_pressed(PLAY) {
+            if playing {
+                stop_playback(&mut controller, &transform);
+                recorder.state = CameraPathState::Idle;
+            } else if recorder.path.keyframes.is_empty() {
+                warn!("There is no camera path to play back, record or load one first");
+            } else {
+                controller.enabled = false;
+                recorder.state = CameraPathState::Playing { time: 0.0 };
+            }
+        }
+
+        match &mut recorder.state {
+            CameraPathState::Idle => {}
+            CameraPathState::Recording { time } => {
+                *time += dt;
+                if *time - recorder.path.duration() >= recorder.record_interval {
+                    recorder.path.keyframes.push(CameraKeyframe {
+                        time: *time,
+                        transform: *transform,
+                    });
+                }
+            }
+            CameraPathState::Playing { time } => {
+                *time += dt * recorder.speed;
+                let duration = recorder.path.duration();
+                if *time > duration {
+                    if recorder.looping && duration > 0.0 {
+                        *time %= duration;
+                    } else {
+                        *time = duration;
+                    }
+                }
+                if let Some(sample) = recorder.path.sample(*time) {
+                    *transform = sample;
+                }
+                if !recorder.looping && *time >= duration {
+                    stop_playback(&mut controller, &transform);
+                    recorder.state = CameraPathState::Idle;
+                }
+            }
+        }
+    }
+}
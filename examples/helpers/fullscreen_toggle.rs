@@ -0,0 +1,82 @@
+//! A plugin that toggles borderless fullscreen with a key press, and restores the window's
+//! previous size and position when returning to windowed mode.
+//! To use in your own application:
+//! - Copy the code for the [`FullscreenTogglePlugin`] and add the plugin to your App.
+//! - The toggle key is registered in the [`HotkeyRegistry`](super::hotkeys::HotkeyRegistry),
+//!   so the `hotkeys` helper needs to be included as well.
+//!
+//! Simply switching [`Window::mode`] back to [`WindowMode::Windowed`] keeps the window at the
+//! size of the monitor, because the window's resolution is updated while it is fullscreen.
+
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, WindowMode, WindowPosition};
+
+use super::hotkeys::{hotkey_just_pressed, RegisterHotkey};
+
+pub struct FullscreenTogglePlugin {
+    /// The default key that toggles fullscreen on the focused window.
+    pub key: KeyCode,
+}
+
+impl Default for FullscreenTogglePlugin {
+    fn default() -> Self {
+        Self { key: KeyCode::F11 }
+    }
+}
+
+impl Plugin for FullscreenTogglePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_hotkey("toggle_fullscreen", self.key)
+            .add_systems(
+                Update,
+                toggle_fullscreen.run_if(hotkey_just_pressed("toggle_fullscreen")),
+            );
+    }
+}
+
+/// The geometry of a window right before it entered fullscreen.
+#[derive(Component)]
+struct WindowedGeometry {
+    /// The logical size of the window.
+    ///
+    /// Storing the logical rather than the physical size keeps the window the same apparent
+    /// size if it ends up on a monitor with a different scale factor.
+    size: Vec2,
+    position: WindowPosition,
+    /// The scale factor of the monitor the window was on.
+    scale_factor: f32,
+}
+
+fn toggle_fullscreen(
+    mut commands: Commands,
+    mut windows: Query<(Entity, &mut Window, Option<&WindowedGeometry>)>,
+) {
+    for (entity, mut window, geometry) in &mut windows {
+        if !window.focused {
+            continue;
+        }
+
+        if window.mode == WindowMode::Windowed {
+            commands.entity(entity).insert(WindowedGeometry {
+                size: Vec2::new(window.width(), window.height()),
+                position: window.position,
+                scale_factor: window.scale_factor(),
+            });
+            window.mode = WindowMode::BorderlessFullscreen;
+        } else {
+            window.mode = WindowMode::Windowed;
+            if let Some(geometry) = geometry {
+                window.resolution.set(geometry.size.x, geometry.size.y);
+                // A different scale factor means the window was moved to another monitor while
+                // fullscreen, so its old position would put it back on the previous monitor,
+                // which may not even be connected anymore. Center it on its current one instead.
+                window.position = if window.scale_factor() == geometry.scale_factor {
+                    geometry.position
+                } else {
+                    WindowPosition::Centered(MonitorSelection::Current)
+                };
+                commands.entity(entity).remove::<WindowedGeometry>();
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! A small registry of named keyboard shortcuts, so that example helpers combined in one app
+//! don't silently fight over the same keys.
+//! To use in your own application:
+//! - Copy the code for the [`HotkeysPlugin`] and add the plugin to your App.
+//! - Register actions with [`RegisterHotkey::register_hotkey`], and trigger systems with the
+//!   [`hotkey_just_pressed`] run condition instead of reading the key directly.
+
+use bevy::prelude::*;
+
+pub struct HotkeysPlugin;
+
+impl Plugin for HotkeysPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HotkeyRegistry>()
+            .add_systems(PostStartup, warn_hotkey_conflicts);
+    }
+}
+
+/// Maps named actions to the key that triggers them.
+#[derive(Resource, Default)]
+pub struct HotkeyRegistry {
+    bindings: Vec<(&'static str, KeyCode)>,
+}
+
+impl HotkeyRegistry {
+    /// Registers `action` with its default key.
+    ///
+    /// If the action is already registered, its current binding is kept, so registering it
+    /// again never undoes a rebinding.
+    pub fn register(&mut self, action: &'static str, default_key: KeyCode) {
+        if self.key(action).is_none() {
+            self.bindings.push((action, default_key));
+        }
+    }
+
+    /// Binds `action` to `key`, warning if another action already uses that key.
+    // Not every app lets the user rebind keys.
+    #[allow(dead_code)]
+    pub fn rebind(&mut self, action: &'static str, key: KeyCode) {
+        let Some(binding) = self.bindings.iter_mut().find(|(name, _)| *name == action) else {
+            warn!("Cannot rebind unknown hotkey action \"{action}\"");
+            return;
+        };
+        binding.1 = key;
+
+        for (other, _) in self
+            .bindings
+            .iter()
+            .filter(|(other, other_key)| *other != action && *other_key == key)
+        {
+            warn!("Hotkey {key:?} is now bound to both \"{action}\" and \"{other}\"");
+        }
+    }
+
+    /// The key `action` is bound to, if it is registered.
+    pub fn key(&self, action: &str) -> Option<KeyCode> {
+        self.bindings
+            .iter()
+            .find(|(name, _)| *name == action)
+            .map(|(_, key)| *key)
+    }
+
+    /// Whether the key bound to `action` was pressed this frame.
+    pub fn just_pressed(&self, action: &str, input: &ButtonInput<KeyCode>) -> bool {
+        self.key(action).is_some_and(|key| input.just_pressed(key))
+    }
+
+    /// Every key bound to more than one action, along with those actions.
+    pub fn conflicts(&self) -> Vec<(KeyCode, Vec<&'static str>)> {
+        let mut keys: Vec<(KeyCode, Vec<&'static str>)> = Vec::new();
+        for (action, key) in &self.bindings {
+            match keys.iter_mut().find(|(other_key, _)| other_key == key) {
+                Some((_, actions)) => actions.push(action),
+                None => keys.push((*key, vec![action])),
+            }
+        }
+        keys.retain(|(_, actions)| actions.len() > 1);
+        keys
+    }
+}
+
+/// Adds [`App::register_hotkey`](RegisterHotkey::register_hotkey).
+pub trait RegisterHotkey {
+    /// Registers a hotkey action with its default key, see [`HotkeyRegistry::register`].
+    fn register_hotkey(&mut self, action: &'static str, default_key: KeyCode) -> &mut Self;
+}
+
+impl RegisterHotkey for App {
+    fn register_hotkey(&mut self, action: &'static str, default_key: KeyCode) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(HotkeyRegistry::default)
+            .register(action, default_key);
+        self
+    }
+}
+
+/// Run condition that is active if the key bound to `action` was just pressed.
+// Some apps check several hotkeys within one system instead.
+#[allow(dead_code)]
+pub fn hotkey_just_pressed(
+    action: &'static str,
+) -> impl FnMut(Res<HotkeyRegistry>, Res<ButtonInput<KeyCode>>) -> bool + Clone {
+    move |registry: Res<HotkeyRegistry>, input: Res<ButtonInput<KeyCode>>| {
+        registry.just_pressed(action, &input)
+    }
+}
+
+fn warn_hotkey_conflicts(registry: Res<HotkeyRegistry>) {
+    for (key, actions) in registry.conflicts() {
+        warn!("Hotkey {key:?} is bound to several actions: {actions:?}");
+    }
+}
@@ -15,6 +15,10 @@ use bevy::{
 
 #[path = "../../helpers/camera_controller.rs"]
 mod camera_controller;
+#[path = "../../helpers/camera_path.rs"]
+mod camera_path;
+#[path = "../../helpers/hotkeys.rs"]
+mod hotkeys;
 
 #[cfg(feature = "animation")]
 mod animation_plugin;
@@ -22,6 +26,8 @@ mod morph_viewer_plugin;
 mod scene_viewer_plugin;
 
 use camera_controller::{CameraController, CameraControllerPlugin};
+use camera_path::{CameraPathPlugin, CameraPathRecorder};
+use hotkeys::HotkeysPlugin;
 use morph_viewer_plugin::MorphViewerPlugin;
 use scene_viewer_plugin::{SceneHandle, SceneViewerPlugin};
 
@@ -40,7 +46,9 @@ fn main() {
                 file_path: std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string()),
                 ..default()
             }),
+        HotkeysPlugin,
         CameraControllerPlugin,
+        CameraPathPlugin,
         SceneViewerPlugin,
         MorphViewerPlugin,
     ))
@@ -122,8 +130,8 @@ fn setup_scene_after_load(
             ..default()
         };
 
-        // Display the controls of the scene viewer
-        info!("{}", camera_controller);
+        // Display the controls of the scene viewer. The camera controller and the camera path
+        // recorder log their own.
         info!("{}", *scene_handle);
 
         commands.spawn((
@@ -147,6 +155,7 @@ fn setup_scene_after_load(
                 intensity: 150.0,
             },
             camera_controller,
+            CameraPathRecorder::default(),
         ));
 
         // Spawn a default light if the scene does not have one
@@ -1,12 +1,20 @@
 //! Uses two windows to visualize a 3D model from different angles.
+//!
+//! The first window is vsynced while the second one is uncapped. Press V to toggle vsync on the
+//! focused window.
 
-use bevy::{prelude::*, render::camera::RenderTarget, window::WindowRef};
+use bevy::{
+    prelude::*,
+    render::camera::RenderTarget,
+    window::{PresentMode, WindowRef},
+};
 
 fn main() {
     App::new()
         // By default, a primary window gets spawned by `WindowPlugin`, contained in `DefaultPlugins`
         .add_plugins(DefaultPlugins)
         .add_systems(Startup, setup_scene)
+        .add_systems(Update, toggle_vsync)
         .run();
 }
 
@@ -33,6 +41,8 @@ fn setup_scene(mut commands: Commands, asset_server: Res<AssetServer>) {
     let second_window = commands
         .spawn(Window {
             title: "Second window".to_owned(),
+            // Each window has its own present mode, independent of the primary window
+            present_mode: PresentMode::AutoNoVsync,
             ..default()
         })
         .id();
@@ -66,3 +76,23 @@ fn setup_scene(mut commands: Commands, asset_server: Res<AssetServer>) {
             ));
         });
 }
+
+/// Toggles vsync on the focused window, leaving the other window's present mode untouched.
+fn toggle_vsync(input: Res<ButtonInput<KeyCode>>, mut windows: Query<&mut Window>) {
+    if !input.just_pressed(KeyCode::KeyV) {
+        return;
+    }
+
+    // Key presses are only received while one of the app's windows is focused, but the focus
+    // may have moved elsewhere by the time this runs.
+    let Some(mut window) = windows.iter_mut().find(|window| window.focused) else {
+        return;
+    };
+
+    window.present_mode = if matches!(window.present_mode, PresentMode::AutoVsync) {
+        PresentMode::AutoNoVsync
+    } else {
+        PresentMode::AutoVsync
+    };
+    info!("{}: PRESENT_MODE: {:?}", window.title, window.present_mode);
+}
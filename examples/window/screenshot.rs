@@ -2,29 +2,40 @@
 
 use bevy::prelude::*;
 use bevy::render::view::screenshot::ScreenshotManager;
-use bevy::window::PrimaryWindow;
+
+#[path = "../helpers/hotkeys.rs"]
+mod hotkeys;
+
+use hotkeys::{hotkey_just_pressed, HotkeyRegistry, HotkeysPlugin, RegisterHotkey};
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, HotkeysPlugin))
+        .register_hotkey("take_screenshot", KeyCode::Space)
         .add_systems(Startup, setup)
-        .add_systems(Update, screenshot_on_spacebar)
+        .add_systems(
+            Update,
+            take_screenshot.run_if(hotkey_just_pressed("take_screenshot")),
+        )
         .run();
 }
 
-fn screenshot_on_spacebar(
-    input: Res<ButtonInput<KeyCode>>,
-    main_window: Query<Entity, With<PrimaryWindow>>,
+fn take_screenshot(
+    windows: Query<(Entity, &Window)>,
     mut screenshot_manager: ResMut<ScreenshotManager>,
     mut counter: Local<u32>,
 ) {
-    if input.just_pressed(KeyCode::Space) {
-        let path = format!("./screenshot-{}.png", *counter);
-        *counter += 1;
-        screenshot_manager
-            .save_screenshot_to_disk(main_window.single(), path)
-            .unwrap();
-    }
+    // Capture the window that received the key press. There may be several windows,
+    // or none at all if they have all been closed.
+    let Some((window, _)) = windows.iter().find(|(_, window)| window.focused) else {
+        return;
+    };
+
+    let path = format!("./screenshot-{}.png", *counter);
+    *counter += 1;
+    screenshot_manager
+        .save_screenshot_to_disk(window, path)
+        .unwrap();
 }
 
 /// set up a simple 3D scene
@@ -32,6 +43,7 @@ fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    hotkeys: Res<HotkeyRegistry>,
 ) {
     // plane
     commands.spawn(PbrBundle {
@@ -63,7 +75,10 @@ fn setup(
 
     commands.spawn(
         TextBundle::from_section(
-            "Press <spacebar> to save a screenshot to disk",
+            format!(
+                "Press {:?} to save a screenshot to disk",
+                hotkeys.key("take_screenshot").unwrap()
+            ),
             TextStyle::default(),
         )
         .with_style(Style {
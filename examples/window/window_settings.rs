@@ -1,12 +1,19 @@
 //! Illustrates how to change window settings and shows how to affect
 //! the mouse pointer in various ways.
 
+#[path = "../helpers/fullscreen_toggle.rs"]
+mod fullscreen_toggle;
+#[path = "../helpers/hotkeys.rs"]
+mod hotkeys;
+
 use bevy::{
     core::FrameCount,
     diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
     prelude::*,
     window::{CursorGrabMode, PresentMode, WindowLevel, WindowTheme},
 };
+use fullscreen_toggle::FullscreenTogglePlugin;
+use hotkeys::{hotkey_just_pressed, HotkeyRegistry, HotkeysPlugin, RegisterHotkey};
 
 fn main() {
     App::new()
@@ -36,17 +43,27 @@ fn main() {
             }),
             LogDiagnosticsPlugin::default(),
             FrameTimeDiagnosticsPlugin,
+            HotkeysPlugin,
+            // Toggles fullscreen with F11, restoring the window's size and position afterwards
+            FullscreenTogglePlugin::default(),
         ))
+        .register_hotkey("toggle_vsync", KeyCode::KeyV)
+        .register_hotkey("switch_level", KeyCode::KeyT)
+        .register_hotkey("toggle_cursor", KeyCode::Space)
+        .register_hotkey("toggle_theme", KeyCode::KeyF)
+        .register_hotkey("toggle_minimize", KeyCode::Digit1)
+        .register_hotkey("toggle_maximize", KeyCode::Digit2)
+        .register_hotkey("toggle_close", KeyCode::Digit3)
         .add_systems(
             Update,
             (
                 change_title,
-                toggle_theme,
-                toggle_cursor,
-                toggle_vsync,
+                toggle_theme.run_if(hotkey_just_pressed("toggle_theme")),
+                toggle_cursor.run_if(hotkey_just_pressed("toggle_cursor")),
+                toggle_vsync.run_if(hotkey_just_pressed("toggle_vsync")),
                 toggle_window_controls,
                 cycle_cursor_icon,
-                switch_level,
+                switch_level.run_if(hotkey_just_pressed("switch_level")),
                 make_visible,
             ),
         )
@@ -65,17 +82,15 @@ fn make_visible(mut window: Query<&mut Window>, frames: Res<FrameCount>) {
 
 /// This system toggles the vsync mode when pressing the button V.
 /// You'll see fps increase displayed in the console.
-fn toggle_vsync(input: Res<ButtonInput<KeyCode>>, mut windows: Query<&mut Window>) {
-    if input.just_pressed(KeyCode::KeyV) {
-        let mut window = windows.single_mut();
+fn toggle_vsync(mut windows: Query<&mut Window>) {
+    let mut window = windows.single_mut();
 
-        window.present_mode = if matches!(window.present_mode, PresentMode::AutoVsync) {
-            PresentMode::AutoNoVsync
-        } else {
-            PresentMode::AutoVsync
-        };
-        info!("PRESENT_MODE: {:?}", window.present_mode);
-    }
+    window.present_mode = if matches!(window.present_mode, PresentMode::AutoVsync) {
+        PresentMode::AutoNoVsync
+    } else {
+        PresentMode::AutoVsync
+    };
+    info!("PRESENT_MODE: {:?}", window.present_mode);
 }
 
 /// This system switches the window level when pressing the T button
@@ -86,17 +101,15 @@ fn toggle_vsync(input: Res<ButtonInput<KeyCode>>, mut windows: Query<&mut Window
 /// [documentation](https://docs.rs/bevy/latest/bevy/prelude/struct.Window.html#structfield.window_level)
 /// for more details.
 
-fn switch_level(input: Res<ButtonInput<KeyCode>>, mut windows: Query<&mut Window>) {
-    if input.just_pressed(KeyCode::KeyT) {
-        let mut window = windows.single_mut();
+fn switch_level(mut windows: Query<&mut Window>) {
+    let mut window = windows.single_mut();
 
-        window.window_level = match window.window_level {
-            WindowLevel::AlwaysOnBottom => WindowLevel::Normal,
-            WindowLevel::Normal => WindowLevel::AlwaysOnTop,
-            WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnBottom,
-        };
-        info!("WINDOW_LEVEL: {:?}", window.window_level);
-    }
+    window.window_level = match window.window_level {
+        WindowLevel::AlwaysOnBottom => WindowLevel::Normal,
+        WindowLevel::Normal => WindowLevel::AlwaysOnTop,
+        WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnBottom,
+    };
+    info!("WINDOW_LEVEL: {:?}", window.window_level);
 }
 
 /// This system toggles the window controls when pressing buttons 1, 2 and 3
@@ -104,10 +117,14 @@ fn switch_level(input: Res<ButtonInput<KeyCode>>, mut windows: Query<&mut Window
 /// This feature only works on some platforms. Please check the
 /// [documentation](https://docs.rs/bevy/latest/bevy/prelude/struct.Window.html#structfield.enabled_buttons)
 /// for more details.
-fn toggle_window_controls(input: Res<ButtonInput<KeyCode>>, mut windows: Query<&mut Window>) {
-    let toggle_minimize = input.just_pressed(KeyCode::Digit1);
-    let toggle_maximize = input.just_pressed(KeyCode::Digit2);
-    let toggle_close = input.just_pressed(KeyCode::Digit3);
+fn toggle_window_controls(
+    hotkeys: Res<HotkeyRegistry>,
+    input: Res<ButtonInput<KeyCode>>,
+    mut windows: Query<&mut Window>,
+) {
+    let toggle_minimize = hotkeys.just_pressed("toggle_minimize", &input);
+    let toggle_maximize = hotkeys.just_pressed("toggle_maximize", &input);
+    let toggle_close = hotkeys.just_pressed("toggle_close", &input);
 
     if toggle_minimize || toggle_maximize || toggle_close {
         let mut window = windows.single_mut();
@@ -133,29 +150,25 @@ fn change_title(mut windows: Query<&mut Window>, time: Res<Time>) {
     );
 }
 
-fn toggle_cursor(mut windows: Query<&mut Window>, input: Res<ButtonInput<KeyCode>>) {
-    if input.just_pressed(KeyCode::Space) {
-        let mut window = windows.single_mut();
+fn toggle_cursor(mut windows: Query<&mut Window>) {
+    let mut window = windows.single_mut();
 
-        window.cursor.visible = !window.cursor.visible;
-        window.cursor.grab_mode = match window.cursor.grab_mode {
-            CursorGrabMode::None => CursorGrabMode::Locked,
-            CursorGrabMode::Locked | CursorGrabMode::Confined => CursorGrabMode::None,
-        };
-    }
+    window.cursor.visible = !window.cursor.visible;
+    window.cursor.grab_mode = match window.cursor.grab_mode {
+        CursorGrabMode::None => CursorGrabMode::Locked,
+        CursorGrabMode::Locked | CursorGrabMode::Confined => CursorGrabMode::None,
+    };
 }
 
 // This system will toggle the color theme used by the window
-fn toggle_theme(mut windows: Query<&mut Window>, input: Res<ButtonInput<KeyCode>>) {
-    if input.just_pressed(KeyCode::KeyF) {
-        let mut window = windows.single_mut();
+fn toggle_theme(mut windows: Query<&mut Window>) {
+    let mut window = windows.single_mut();
 
-        if let Some(current_theme) = window.window_theme {
-            window.window_theme = match current_theme {
-                WindowTheme::Light => Some(WindowTheme::Dark),
-                WindowTheme::Dark => Some(WindowTheme::Light),
-            };
-        }
+    if let Some(current_theme) = window.window_theme {
+        window.window_theme = match current_theme {
+            WindowTheme::Light => Some(WindowTheme::Dark),
+            WindowTheme::Dark => Some(WindowTheme::Light),
+        };
     }
 }
 